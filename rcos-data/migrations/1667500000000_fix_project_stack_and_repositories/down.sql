@@ -0,0 +1,15 @@
+ALTER TABLE projects ALTER COLUMN repository_urls DROP DEFAULT;
+
+-- Remove the rows copied from the project array columns.
+DELETE FROM project_repositories
+USING projects
+WHERE project_repositories.project_id = projects.project_id
+  AND project_repositories.url::text = ANY(projects.repository_urls::text[]);
+
+DELETE FROM project_stack
+USING projects
+WHERE project_stack.project_id = projects.project_id
+  AND project_stack.language = ANY(projects.stack::text[]);
+
+ALTER TABLE project_stack ALTER COLUMN language TYPE url;
+ALTER TABLE project_repositories RENAME COLUMN url TO language;
@@ -0,0 +1,19 @@
+-- The project repositories table was created with the repository URL column
+-- misnamed as "language", and the project stack table was created with the
+-- url domain for technology names. Fix both.
+ALTER TABLE project_repositories RENAME COLUMN language TO url;
+COMMENT ON COLUMN project_repositories.url IS 'URL of a repository for this project';
+
+ALTER TABLE project_stack ALTER COLUMN language TYPE TEXT;
+COMMENT ON COLUMN project_stack.language IS 'Name of a technology used by this project';
+
+-- Copy the existing project stacks and repositories into the new tables.
+INSERT INTO project_stack(project_id, language)
+SELECT project_id, unnest(stack) FROM projects;
+
+INSERT INTO project_repositories(project_id, url)
+SELECT project_id, unnest(repository_urls) FROM projects;
+
+-- Stacks and repositories are now stored in their own tables, so new projects
+-- no longer write the array columns.
+ALTER TABLE projects ALTER COLUMN repository_urls SET DEFAULT '{}';
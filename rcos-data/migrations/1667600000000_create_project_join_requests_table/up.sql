@@ -0,0 +1,16 @@
+-- Students request to join a project, and the leads of that project approve
+-- or deny the request. Requests are removed once they are handled.
+CREATE TABLE project_join_requests (
+  semester_id VARCHAR NOT NULL REFERENCES semesters(semester_id),
+  user_id UUID NOT NULL REFERENCES users(id),
+  project_id INTEGER NOT NULL REFERENCES projects(project_id) ON DELETE CASCADE,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+  PRIMARY KEY (semester_id, user_id, project_id),
+
+  FOREIGN KEY (semester_id, user_id) REFERENCES enrollments (semester_id, user_id)
+);
+
+COMMENT ON TABLE project_join_requests IS 'Pending requests by enrolled students to join a project';
+COMMENT ON COLUMN project_join_requests.user_id IS 'User ID of the student requesting to join';
+COMMENT ON COLUMN project_join_requests.project_id IS 'The project the student wants to join';
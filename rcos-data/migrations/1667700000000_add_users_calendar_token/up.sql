@@ -0,0 +1,5 @@
+-- Secret token used in the URL of each user's meeting calendar feed. Calendar
+-- apps cannot authenticate, so the token stands in for the user's login. It
+-- can be regenerated if the feed URL leaks.
+ALTER TABLE users ADD COLUMN calendar_token UUID UNIQUE NOT NULL DEFAULT gen_random_uuid();
+COMMENT ON COLUMN users.calendar_token IS 'Secret token for the user''s meeting calendar feed';
@@ -0,0 +1,2 @@
+DROP INDEX meetings_series_id_index;
+ALTER TABLE meetings DROP COLUMN series_id;
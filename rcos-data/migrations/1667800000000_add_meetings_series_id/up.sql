@@ -0,0 +1,6 @@
+-- Recurring meetings are created together as a series. Meetings in the same
+-- series share a series ID, so that edits can be applied to the rest of the
+-- series.
+ALTER TABLE meetings ADD COLUMN series_id UUID;
+COMMENT ON COLUMN meetings.series_id IS 'Shared by meetings created together as a recurring series';
+CREATE INDEX meetings_series_id_index ON meetings(series_id);
@@ -0,0 +1,12 @@
+-- Announcements are broadcast to a Discord channel when they are made. The
+-- message is stored so that it can be edited or deleted with the announcement.
+CREATE TABLE announcement_discord_messages (
+  announcement_id INTEGER PRIMARY KEY REFERENCES announcements(announcement_id) ON DELETE CASCADE,
+  channel_id VARCHAR NOT NULL,
+  message_id VARCHAR NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+COMMENT ON TABLE announcement_discord_messages IS 'Discord messages broadcasting announcements';
+COMMENT ON COLUMN announcement_discord_messages.channel_id IS 'ID of the Discord channel the message was sent in';
+COMMENT ON COLUMN announcement_discord_messages.message_id IS 'ID of the Discord message';
@@ -0,0 +1,3 @@
+DROP TABLE project_presentation_criterion_scores;
+DROP TABLE presentation_rubric_criteria;
+DROP TABLE presentation_grading_sessions;
@@ -0,0 +1,42 @@
+-- Coordinators open a grading session for the end of semester project
+-- presentations. Each grader scores every project against the rubric of the
+-- session. The total of a grader's scores for a project is stored in
+-- project_presentation_grades, and the score for each criterion is stored here.
+CREATE TABLE presentation_grading_sessions (
+  semester_id VARCHAR PRIMARY KEY REFERENCES semesters(semester_id),
+  is_open BOOLEAN NOT NULL DEFAULT true,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+COMMENT ON TABLE presentation_grading_sessions IS 'Grading sessions for end of semester project presentations';
+COMMENT ON COLUMN presentation_grading_sessions.is_open IS 'Whether graders can still submit and change scores';
+
+CREATE TABLE presentation_rubric_criteria (
+  criterion_id SERIAL PRIMARY KEY,
+  semester_id VARCHAR NOT NULL REFERENCES presentation_grading_sessions(semester_id) ON DELETE CASCADE,
+  title VARCHAR NOT NULL,
+  description TEXT NOT NULL DEFAULT '',
+  max_score INTEGER NOT NULL CHECK (max_score > 0),
+  created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+  UNIQUE (semester_id, title)
+);
+
+COMMENT ON TABLE presentation_rubric_criteria IS 'Criteria of the rubric for grading project presentations';
+COMMENT ON COLUMN presentation_rubric_criteria.max_score IS 'The highest score a project can get for this criterion';
+
+CREATE TABLE project_presentation_criterion_scores (
+  semester_id VARCHAR NOT NULL,
+  project_id INTEGER NOT NULL,
+  grader_id UUID NOT NULL,
+  criterion_id INTEGER NOT NULL REFERENCES presentation_rubric_criteria(criterion_id) ON DELETE CASCADE,
+  score REAL NOT NULL CHECK (score >= 0.0),
+
+  PRIMARY KEY (semester_id, project_id, grader_id, criterion_id),
+
+  FOREIGN KEY (semester_id, project_id, grader_id)
+    REFERENCES project_presentation_grades (semester_id, project_id, grader_id)
+    ON DELETE CASCADE
+);
+
+COMMENT ON TABLE project_presentation_criterion_scores IS 'Scores for each rubric criterion making up project presentation grades';
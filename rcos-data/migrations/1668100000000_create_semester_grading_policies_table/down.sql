@@ -0,0 +1 @@
+DROP TABLE semester_grading_policies;
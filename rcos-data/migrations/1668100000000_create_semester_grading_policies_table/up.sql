@@ -0,0 +1,20 @@
+-- The policy faculty advisors use to propose grades for a semester. Each
+-- component (attendance, status updates, and presentations) is scored from 0 to
+-- 1 and the proposed grade is the weighted average of the components, out of 100.
+CREATE TABLE semester_grading_policies (
+  semester_id VARCHAR PRIMARY KEY REFERENCES semesters(semester_id) ON UPDATE CASCADE ON DELETE CASCADE,
+  attendance_weight REAL NOT NULL DEFAULT 0.5 CHECK (attendance_weight >= 0),
+  status_update_weight REAL NOT NULL DEFAULT 0.25 CHECK (status_update_weight >= 0),
+  presentation_weight REAL NOT NULL DEFAULT 0.25 CHECK (presentation_weight >= 0),
+  required_attendances INTEGER NOT NULL DEFAULT 20 CHECK (required_attendances > 0),
+  max_bonus_attendances INTEGER NOT NULL DEFAULT 2 CHECK (max_bonus_attendances >= 0),
+  status_update_threshold REAL NOT NULL DEFAULT 0.8 CHECK (status_update_threshold > 0 AND status_update_threshold <= 1),
+  created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+  CHECK (attendance_weight + status_update_weight + presentation_weight > 0)
+);
+
+COMMENT ON TABLE semester_grading_policies IS 'Weights and thresholds used to propose grades for a semester';
+COMMENT ON COLUMN semester_grading_policies.required_attendances IS 'Large and small group meetings attended for full attendance credit';
+COMMENT ON COLUMN semester_grading_policies.max_bonus_attendances IS 'Bonus attendances that count as attended meetings';
+COMMENT ON COLUMN semester_grading_policies.status_update_threshold IS 'Fraction of the status updates of the semester to submit for full credit';
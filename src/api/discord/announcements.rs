@@ -0,0 +1,110 @@
+//! Announcement broadcasts in the RCOS Discord.
+
+use crate::api::discord::{global_discord_client, on_discord_runtime};
+use crate::env::global_config;
+use crate::error::TelescopeError;
+use serde_json::Value;
+use serenity::builder::{CreateEmbed, CreateMessage, EditMessage};
+use serenity::json::hashmap_to_json_map;
+use serenity::model::id::RoleId;
+use serenity::model::Timestamp;
+
+/// Discord limits embed titles to 256 characters.
+const MAX_TITLE_LENGTH: usize = 256;
+
+/// Discord limits embed descriptions to 4096 characters.
+const MAX_DESCRIPTION_LENGTH: usize = 4096;
+
+/// Shorten a string to at most `max` characters, marking it with an ellipsis if it was cut.
+fn truncate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let mut truncated: String = text.chars().take(max - 1).collect();
+    truncated.push('…');
+    return truncated;
+}
+
+/// Build the embed for an announcement. Discord renders markdown in embed descriptions, so the
+/// body is used as is.
+fn make_embed(announcement_id: i64, title: &str, body_markdown: &str) -> CreateEmbed {
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(truncate(title, MAX_TITLE_LENGTH))
+        .description(truncate(body_markdown, MAX_DESCRIPTION_LENGTH))
+        .url(format!(
+            "{}/announcements#announcement-{}",
+            global_config().telescope_url,
+            announcement_id
+        ))
+        .timestamp(Timestamp::now())
+        .footer(|create_footer| {
+            create_footer.text(format!("Telescope {}", env!("CARGO_PKG_VERSION")))
+        });
+    return embed;
+}
+
+/// Post an announcement to the announcement channel in the RCOS Discord, pinging the
+/// announcement role if there is one. Return the channel and message IDs of the posted
+/// message, or `None` if there is no announcement channel configured.
+pub async fn post_announcement(
+    announcement_id: i64,
+    title: &str,
+    body_markdown: &str,
+) -> Result<Option<(u64, u64)>, TelescopeError> {
+    let config = &global_config().discord_config;
+    let channel_id: u64 = match config.announcement_channel_id() {
+        Some(channel_id) => channel_id,
+        None => return Ok(None),
+    };
+
+    let mut builder = CreateMessage::default();
+    builder.set_embed(make_embed(announcement_id, title, body_markdown));
+
+    // Only allow the announcement role to be mentioned.
+    let role: Option<u64> = config.announcement_role_id();
+    if let Some(role_id) = role {
+        builder.content(format!("<@&{}>", role_id));
+    }
+    builder.allowed_mentions(|allowed| allowed.roles(role.map(RoleId)));
+
+    let map = Value::Object(hashmap_to_json_map(builder.0));
+    let message =
+        on_discord_runtime(
+            async move { global_discord_client().send_message(channel_id, &map).await },
+        )
+        .await
+        .map_err(TelescopeError::serenity_error)?;
+
+    return Ok(Some((channel_id, message.id.0)));
+}
+
+/// Update a posted announcement after it is edited. The role is not pinged again.
+pub async fn edit_announcement(
+    channel_id: u64,
+    message_id: u64,
+    announcement_id: i64,
+    title: &str,
+    body_markdown: &str,
+) -> Result<(), TelescopeError> {
+    let mut builder = EditMessage::default();
+    builder.set_embed(make_embed(announcement_id, title, body_markdown));
+
+    let map = Value::Object(hashmap_to_json_map(builder.0));
+    on_discord_runtime(async move {
+        global_discord_client()
+            .edit_message(channel_id, message_id, &map)
+            .await
+    })
+    .await
+    .map_err(TelescopeError::serenity_error)?;
+
+    return Ok(());
+}
+
+/// Delete a posted announcement after it is closed.
+pub async fn delete_announcement(channel_id: u64, message_id: u64) -> Result<(), TelescopeError> {
+    on_discord_runtime(global_discord_client().delete_message(channel_id, message_id))
+        .await
+        .map_err(TelescopeError::serenity_error)
+}
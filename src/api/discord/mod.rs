@@ -2,12 +2,40 @@
 
 use crate::env::global_config;
 use crate::error::TelescopeError;
+use futures::Future;
 use serenity::http::Http;
 use serenity::model::id::RoleId;
+use tokio::runtime::{Builder, Runtime};
+use tokio_compat_02::FutureExt;
+
+pub mod announcements;
 
 lazy_static! {
     static ref DISCORD_API_CLIENT: Http =
-        Http::new_with_token(global_config().as_ref().discord_config.bot_token.as_str());
+        Http::new(global_config().as_ref().discord_config.bot_token.as_str());
+
+    /// Serenity needs a tokio 1 runtime, but actix runs on tokio 0.2. The Discord bot and
+    /// every request made with the Discord API client run on this runtime instead.
+    static ref DISCORD_RUNTIME: Runtime = Builder::new_multi_thread()
+        .thread_name("discord")
+        .enable_all()
+        .build()
+        .expect("Could not build Discord runtime.");
+}
+
+/// Run a future on the Discord runtime and wait for its output. Use this to make requests
+/// with serenity from actix. The future can also make requests to the RCOS API.
+pub async fn on_discord_runtime<F>(future: F) -> F::Output
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    DISCORD_RUNTIME
+        .spawn(future.compat())
+        .await
+        // Pass panics on to the caller. Tasks are only cancelled if the runtime shuts down,
+        // which it never does.
+        .unwrap_or_else(|err| std::panic::resume_unwind(err.into_panic()))
 }
 
 /// Get a reference to the global lazily evaluated static discord api client object.
@@ -21,17 +49,18 @@ pub async fn rcos_discord_verified_role_id() -> Result<Option<RoleId>, Telescope
     let rcos_discord: u64 = global_config().discord_config.rcos_guild_id();
 
     // Get role
-    Ok(global_discord_client()
-        .get_guild_roles(rcos_discord)
-        .await
-        .map_err(|err| {
-            error!("Could not get RCOS Discord Roles. Internal error: {}", err);
-            TelescopeError::serenity_error(err)
-        })?
-        .iter()
-        // We use a simple string comparison for now. We can change this to use
-        // something else later on if needed.
-        .find(|role| role.name.eq_ignore_ascii_case("Verified"))
-        // Extract the ID from the Discord Role.
-        .map(|role| role.id))
+    Ok(
+        on_discord_runtime(global_discord_client().get_guild_roles(rcos_discord))
+            .await
+            .map_err(|err| {
+                error!("Could not get RCOS Discord Roles. Internal error: {}", err);
+                TelescopeError::serenity_error(err)
+            })?
+            .iter()
+            // We use a simple string comparison for now. We can change this to use
+            // something else later on if needed.
+            .find(|role| role.name.eq_ignore_ascii_case("Verified"))
+            // Extract the ID from the Discord Role.
+            .map(|role| role.id),
+    )
 }
@@ -1,6 +1,7 @@
 //! GitHub API V4 queries and mutations.
 
 use crate::api::handle_graphql_response;
+use crate::env::global_config;
 use crate::error::TelescopeError;
 use crate::web::telescope_ua;
 use graphql_client::{GraphQLQuery, Response as GraphQLResponse};
@@ -8,14 +9,34 @@ use oauth2::AccessToken;
 use reqwest::header::{HeaderValue, ACCEPT, USER_AGENT};
 use reqwest::Client;
 
+pub mod repositories;
 pub mod users;
 
-/// The GitHub API endpoint
+/// The default GitHub API endpoint.
 const GITHUB_API_ENDPOINT: &'static str = "https://api.github.com/graphql";
 
 /// The name of this API in error reporting.
 const API_NAME: &'static str = "GitHub API V4";
 
+/// Get the GitHub API endpoint. This is the default endpoint unless overridden in the config.
+fn api_endpoint() -> String {
+    global_config()
+        .github_credentials
+        .api_url
+        .clone()
+        .unwrap_or(GITHUB_API_ENDPOINT.to_string())
+}
+
+/// Get the access token Telescope uses to query the GitHub API on its own behalf, if one is
+/// configured.
+pub fn telescope_access_token() -> Option<AccessToken> {
+    global_config()
+        .github_credentials
+        .api_token
+        .clone()
+        .map(AccessToken::new)
+}
+
 /// Send a GraphQL query to the GitHub API.
 pub async fn send_query<T: GraphQLQuery>(
     auth_token: &AccessToken,
@@ -27,7 +48,7 @@ pub async fn send_query<T: GraphQLQuery>(
     // Make a client, send the request, and return the result.
     return Client::new()
         // POST request to the GitHub GraphQL API endpoint
-        .post(GITHUB_API_ENDPOINT)
+        .post(api_endpoint().as_str())
         // With the JSON of the GraphQL query
         .json(&query)
         // With the user's access token
@@ -0,0 +1,143 @@
+//! Query to get the activity on a GitHub repository during a period of time.
+
+use crate::api::github;
+use crate::error::TelescopeError;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use oauth2::AccessToken;
+use url::Url;
+
+// Import serializable URL type for query types.
+use url::Url as URI;
+
+/// Git timestamps are ISO-8601 encoded.
+type GitTimestamp = DateTime<Utc>;
+
+/// Type representing GraphQL query to get the activity on a repository.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/github/schema.json",
+    query_path = "graphql/github/repositories/activity.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct RepositoryActivity;
+
+use repository_activity::{
+    RepositoryActivityRepositoryDefaultBranchRefTarget as Target, ResponseData, Variables,
+};
+
+/// A GitHub user who authored commits to a repository.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Contributor {
+    /// The GitHub GraphQL node ID of the user. This is the account ID stored for linked
+    /// GitHub accounts.
+    pub github_id: String,
+    /// The user's GitHub login.
+    pub login: String,
+}
+
+/// Summary of the activity on a repository during a period of time.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ActivitySummary {
+    /// The owner and name of the repository, e.g. "rcos/Telescope".
+    pub name_with_owner: String,
+    /// The URL of the repository on GitHub.
+    pub url: String,
+    /// The number of commits to the default branch during the period.
+    pub commits: i64,
+    /// The number of pull requests opened during the period.
+    pub pull_requests_opened: i64,
+    /// The number of issues opened during the period.
+    pub issues_opened: i64,
+    /// The number of currently open pull requests.
+    pub open_pull_requests: i64,
+    /// The number of currently open issues.
+    pub open_issues: i64,
+    /// The GitHub users who authored commits during the period. This only covers the
+    /// first 100 commits.
+    pub contributors: Vec<Contributor>,
+}
+
+/// Get the owner and name of a GitHub repository from its URL. Return `None` if the URL is
+/// not a GitHub repository URL.
+pub fn parse_repository_url(url: &str) -> Option<(String, String)> {
+    let url: Url = Url::parse(url).ok()?;
+    if url.host_str()? != "github.com" {
+        return None;
+    }
+
+    let mut segments = url.path_segments()?.filter(|segment| !segment.is_empty());
+    let owner: String = segments.next()?.to_string();
+    let name: String = segments.next()?.trim_end_matches(".git").to_string();
+    return Some((owner, name));
+}
+
+impl RepositoryActivity {
+    /// Get the activity on a repository between two dates (inclusive). Return `None` if the
+    /// repository does not exist or is not visible.
+    pub async fn get(
+        auth_token: &AccessToken,
+        owner: String,
+        name: String,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Option<ActivitySummary>, TelescopeError> {
+        // GitHub searches use inclusive date ranges.
+        let search_range = format!("repo:{}/{} created:{}..{}", owner, name, start, end);
+
+        let response: ResponseData = github::send_query::<Self>(
+            auth_token,
+            Variables {
+                owner,
+                name,
+                since: DateTime::from_utc(start.and_hms(0, 0, 0), Utc),
+                until: DateTime::from_utc((end + Duration::days(1)).and_hms(0, 0, 0), Utc),
+                pull_request_query: format!("{} is:pr", search_range),
+                issue_query: format!("{} is:issue", search_range),
+            },
+        )
+        .await?;
+
+        let repository = match response.repository {
+            Some(repository) => repository,
+            None => return Ok(None),
+        };
+
+        // Empty repositories do not have a default branch.
+        let history = repository
+            .default_branch_ref
+            .and_then(|branch| match branch.target {
+                Some(Target::Commit(commit)) => Some(commit.history),
+                _ => None,
+            });
+
+        let mut contributors: Vec<Contributor> = Vec::new();
+        if let Some(nodes) = history.as_ref().and_then(|h| h.nodes.as_ref()) {
+            // Commits by authors without a GitHub account are not attributed to anyone.
+            for user in nodes
+                .iter()
+                .flatten()
+                .filter_map(|commit| commit.author.as_ref()?.user.as_ref())
+            {
+                let contributor = Contributor {
+                    github_id: user.id.clone(),
+                    login: user.login.clone(),
+                };
+
+                if !contributors.contains(&contributor) {
+                    contributors.push(contributor);
+                }
+            }
+        }
+
+        return Ok(Some(ActivitySummary {
+            name_with_owner: repository.name_with_owner,
+            url: repository.url.to_string(),
+            commits: history.map(|h| h.total_count).unwrap_or(0),
+            pull_requests_opened: response.pull_requests_opened.issue_count,
+            issues_opened: response.issues_opened.issue_count,
+            open_pull_requests: repository.open_pull_requests.total_count,
+            open_issues: repository.open_issues.total_count,
+            contributors,
+        }));
+    }
+}
@@ -0,0 +1,257 @@
+//! Announcement queries and mutations.
+
+use crate::api::rcos::prelude::*;
+use crate::api::rcos::send_query;
+use crate::error::TelescopeError;
+use chrono::{DateTime, Duration, Utc};
+use std::sync::RwLock;
+
+/// Type representing GraphQL query to get the announcements that are currently shown.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/announcements/active.graphql",
+    response_derives = "Debug,Clone,Serialize,Deserialize"
+)]
+pub struct ActiveAnnouncements;
+
+/// How long the active announcements are cached for. Every page shows the active announcements,
+/// so they are only fetched from the API this often (or after they are changed).
+const ACTIVE_CACHE_SECONDS: i64 = 60;
+
+lazy_static! {
+    /// The cached active announcements and the time they expire.
+    static ref ACTIVE_CACHE: RwLock<
+        Option<(
+            Vec<active_announcements::ActiveAnnouncementsAnnouncements>,
+            DateTime<Utc>,
+        )>,
+    > = RwLock::new(None);
+}
+
+impl ActiveAnnouncements {
+    /// Get the announcements that are currently shown, most recent first. These are cached
+    /// briefly.
+    pub async fn get(
+    ) -> Result<Vec<active_announcements::ActiveAnnouncementsAnnouncements>, TelescopeError> {
+        // Use the cached announcements if they have not expired.
+        if let Some((announcements, expiration)) = ACTIVE_CACHE.read().unwrap().as_ref() {
+            if *expiration > Utc::now() {
+                return Ok(announcements.clone());
+            }
+        }
+
+        let announcements = send_query::<Self>(active_announcements::Variables {
+            now: Utc::now(),
+            today: Utc::today().naive_utc(),
+        })
+        .await?
+        .announcements;
+
+        let expiration: DateTime<Utc> = Utc::now() + Duration::seconds(ACTIVE_CACHE_SECONDS);
+        *ACTIVE_CACHE.write().unwrap() = Some((announcements.clone(), expiration));
+        return Ok(announcements);
+    }
+
+    /// Clear the cached active announcements, so that changes are shown immediately.
+    fn invalidate() {
+        *ACTIVE_CACHE.write().unwrap() = None;
+    }
+}
+
+/// Type representing GraphQL query to get every announcement.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/announcements/archive.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct AnnouncementArchive;
+
+impl AnnouncementArchive {
+    /// Get every announcement, most recent first.
+    pub async fn get(
+    ) -> Result<Vec<announcement_archive::AnnouncementArchiveAnnouncements>, TelescopeError> {
+        send_query::<Self>(announcement_archive::Variables {})
+            .await
+            .map(|response| response.announcements)
+    }
+}
+
+/// Type representing GraphQL query to get an announcement by ID.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/announcements/get_by_id.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct Announcement;
+
+impl Announcement {
+    /// Get an announcement by ID, if it exists.
+    pub async fn get(
+        announcement_id: i64,
+    ) -> Result<Option<announcement::AnnouncementAnnouncement>, TelescopeError> {
+        send_query::<Self>(announcement::Variables { announcement_id })
+            .await
+            .map(|response| response.announcement)
+    }
+}
+
+/// Type representing GraphQL query to get the semesters announcements can be made for.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/announcements/semesters.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct AnnouncementSemesters;
+
+impl AnnouncementSemesters {
+    /// Get every semester, most recent first.
+    pub async fn get(
+    ) -> Result<Vec<announcement_semesters::AnnouncementSemestersSemesters>, TelescopeError> {
+        send_query::<Self>(announcement_semesters::Variables {})
+            .await
+            .map(|response| response.semesters)
+    }
+}
+
+/// Type representing GraphQL mutation to make an announcement.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/announcements/create.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct CreateAnnouncement;
+
+impl CreateAnnouncement {
+    /// Make an announcement. Return the ID of the created announcement.
+    pub async fn execute(
+        semester_id: String,
+        title: String,
+        body_markdown: String,
+        close_date_time: Option<DateTime<Utc>>,
+    ) -> Result<Option<i64>, TelescopeError> {
+        send_query::<Self>(create_announcement::Variables {
+            semester_id,
+            title,
+            body_markdown,
+            close_date_time,
+        })
+        .await
+        .map(|response| {
+            ActiveAnnouncements::invalidate();
+            response
+                .insert_announcements_one
+                .map(|obj| obj.announcement_id)
+        })
+    }
+}
+
+/// Type representing GraphQL mutation to edit an announcement.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/announcements/edit.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct EditAnnouncement;
+
+impl EditAnnouncement {
+    /// Edit an announcement. Return its ID if it exists.
+    pub async fn execute(
+        announcement_id: i64,
+        semester_id: String,
+        title: String,
+        body_markdown: String,
+        close_date_time: Option<DateTime<Utc>>,
+    ) -> Result<Option<i64>, TelescopeError> {
+        send_query::<Self>(edit_announcement::Variables {
+            announcement_id,
+            semester_id,
+            title,
+            body_markdown,
+            close_date_time,
+        })
+        .await
+        .map(|response| {
+            ActiveAnnouncements::invalidate();
+            response
+                .update_announcements_by_pk
+                .map(|obj| obj.announcement_id)
+        })
+    }
+}
+
+/// Type representing GraphQL mutation to close an announcement.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/announcements/close.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct CloseAnnouncement;
+
+impl CloseAnnouncement {
+    /// Close an announcement now. Return its ID if it exists.
+    pub async fn execute(announcement_id: i64) -> Result<Option<i64>, TelescopeError> {
+        send_query::<Self>(close_announcement::Variables {
+            announcement_id,
+            now: Utc::now(),
+        })
+        .await
+        .map(|response| {
+            ActiveAnnouncements::invalidate();
+            response
+                .update_announcements_by_pk
+                .map(|obj| obj.announcement_id)
+        })
+    }
+}
+
+/// Type representing GraphQL mutation to save the Discord message broadcasting an announcement.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/announcements/save_discord_message.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct SaveAnnouncementDiscordMessage;
+
+impl SaveAnnouncementDiscordMessage {
+    /// Save the Discord message broadcasting an announcement, replacing any previous one.
+    pub async fn execute(
+        announcement_id: i64,
+        channel_id: u64,
+        message_id: u64,
+    ) -> Result<(), TelescopeError> {
+        send_query::<Self>(save_announcement_discord_message::Variables {
+            announcement_id,
+            channel_id: channel_id.to_string(),
+            message_id: message_id.to_string(),
+        })
+        .await
+        .map(|_| ())
+    }
+}
+
+/// Type representing GraphQL mutation to forget the Discord message broadcasting an
+/// announcement.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/announcements/delete_discord_message.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct DeleteAnnouncementDiscordMessage;
+
+impl DeleteAnnouncementDiscordMessage {
+    /// Forget the Discord message broadcasting an announcement.
+    pub async fn execute(announcement_id: i64) -> Result<(), TelescopeError> {
+        send_query::<Self>(delete_announcement_discord_message::Variables { announcement_id })
+            .await
+            .map(|_| ())
+    }
+}
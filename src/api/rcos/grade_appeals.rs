@@ -0,0 +1,111 @@
+//! Final grade appeal queries and mutations.
+
+use crate::api::rcos::prelude::*;
+use crate::api::rcos::send_query;
+use crate::error::TelescopeError;
+use chrono::Utc;
+
+/// Type representing GraphQL query to get a user's past enrollments and grade appeals.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/grade_appeals/for_user.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct UserGradeAppeals;
+
+impl UserGradeAppeals {
+    /// Get a user's enrollments in semesters that have ended, with any appeals they filed.
+    pub async fn get(
+        user_id: uuid,
+    ) -> Result<Vec<user_grade_appeals::UserGradeAppealsEnrollments>, TelescopeError> {
+        send_query::<Self>(user_grade_appeals::Variables {
+            user_id,
+            now: Utc::today().naive_utc(),
+        })
+        .await
+        .map(|response| response.enrollments)
+    }
+}
+
+/// Type representing GraphQL mutation to file a final grade appeal.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/grade_appeals/submit.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct SubmitGradeAppeal;
+
+impl SubmitGradeAppeal {
+    /// File a final grade appeal. If the user already filed one for this semester and it has
+    /// not been handled, it is replaced. Return `None` if the existing appeal was handled.
+    pub async fn execute(
+        semester_id: String,
+        user_id: uuid,
+        expected_grade: String,
+        reason: String,
+    ) -> Result<Option<()>, TelescopeError> {
+        send_query::<Self>(submit_grade_appeal::Variables {
+            semester_id,
+            user_id,
+            expected_grade,
+            reason,
+        })
+        .await
+        .map(|response| response.insert_final_grade_appeal_one.map(|_| ()))
+    }
+}
+
+/// Type representing GraphQL query to get final grade appeals to triage.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/grade_appeals/queue.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct GradeAppealQueue;
+
+impl GradeAppealQueue {
+    /// Get unhandled final grade appeals, and handled ones too if requested.
+    pub async fn get(
+        include_handled: bool,
+    ) -> Result<Vec<grade_appeal_queue::GradeAppealQueueFinalGradeAppeal>, TelescopeError> {
+        let handled: Vec<bool> = if include_handled {
+            vec![false, true]
+        } else {
+            vec![false]
+        };
+
+        send_query::<Self>(grade_appeal_queue::Variables { handled })
+            .await
+            .map(|response| response.final_grade_appeal)
+    }
+}
+
+/// Type representing GraphQL mutation to resolve a final grade appeal.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/grade_appeals/resolve.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct ResolveGradeAppeal;
+
+impl ResolveGradeAppeal {
+    /// Set the final grade on the appealed enrollment and mark the appeal handled. Return `None`
+    /// if the appeal does not exist.
+    pub async fn execute(
+        semester_id: String,
+        user_id: uuid,
+        final_grade: Option<f64>,
+    ) -> Result<Option<()>, TelescopeError> {
+        send_query::<Self>(resolve_grade_appeal::Variables {
+            semester_id,
+            user_id,
+            final_grade,
+        })
+        .await
+        .map(|response| response.update_final_grade_appeal_by_pk.map(|_| ()))
+    }
+}
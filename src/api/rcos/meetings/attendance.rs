@@ -0,0 +1,151 @@
+//! Meeting attendance queries and mutations.
+
+use crate::api::rcos::prelude::*;
+use crate::api::rcos::send_query;
+use crate::error::TelescopeError;
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+
+/// The number of characters in a generated attendance code.
+const ATTENDANCE_CODE_LENGTH: usize = 6;
+
+/// Type representing GraphQL query to get the context needed to check a user into a meeting.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/meetings/attendance/context.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct AttendanceContext;
+
+impl AttendanceContext {
+    /// Get the attendance context of a meeting for a given user. Return `None` if the meeting
+    /// does not exist.
+    pub async fn get(
+        meeting_id: i64,
+        user_id: uuid,
+    ) -> Result<Option<attendance_context::AttendanceContextMeeting>, TelescopeError> {
+        send_query::<Self>(attendance_context::Variables {
+            meeting_id,
+            user_id,
+        })
+        .await
+        .map(|response| response.meeting)
+    }
+}
+
+/// Type representing GraphQL mutation to record a user's attendance at a meeting.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/meetings/attendance/attend.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct Attend;
+
+impl Attend {
+    /// Record that a user attended a meeting. This does nothing if the user is already
+    /// marked present.
+    pub async fn execute(meeting_id: i64, user_id: uuid) -> Result<(), TelescopeError> {
+        send_query::<Self>(attend::Variables {
+            meeting_id,
+            user_id,
+        })
+        .await
+        .map(|_| ())
+    }
+}
+
+/// Type representing GraphQL mutation to set the attendance code of a meeting.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/meetings/attendance/set_code.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct SetAttendanceCode;
+
+impl SetAttendanceCode {
+    /// Generate a new random attendance code and store it on a meeting. This replaces any
+    /// existing code. Return the new code if the meeting exists.
+    pub async fn rotate(meeting_id: i64) -> Result<Option<String>, TelescopeError> {
+        // Generate an uppercase alphanumeric code that is easy to read off a slide.
+        let code: String = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(ATTENDANCE_CODE_LENGTH)
+            .map(|c| (c as char).to_ascii_uppercase())
+            .collect();
+
+        // Store it.
+        let response = send_query::<Self>(set_attendance_code::Variables {
+            meeting_id,
+            code: Some(code.clone()),
+        })
+        .await?;
+
+        return Ok(response.update_meetings_by_pk.map(|_| code));
+    }
+}
+
+/// Type representing GraphQL query to get the attendance roster of a meeting.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/meetings/attendance/roster.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct AttendanceRoster;
+
+impl AttendanceRoster {
+    /// Get the attendance roster for a meeting. Return `None` if the meeting does not exist.
+    pub async fn get(
+        meeting_id: i64,
+    ) -> Result<Option<attendance_roster::AttendanceRosterMeeting>, TelescopeError> {
+        send_query::<Self>(attendance_roster::Variables { meeting_id })
+            .await
+            .map(|response| response.meeting)
+    }
+}
+
+/// Type representing GraphQL mutation to manually mark a user present at a meeting.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/meetings/attendance/add.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct AddAttendance;
+
+impl AddAttendance {
+    /// Manually mark a user present at a meeting. This does nothing if the user is already
+    /// marked present.
+    pub async fn execute(meeting_id: i64, user_id: uuid) -> Result<(), TelescopeError> {
+        send_query::<Self>(add_attendance::Variables {
+            meeting_id,
+            user_id,
+        })
+        .await
+        .map(|_| ())
+    }
+}
+
+/// Type representing GraphQL mutation to remove a user's attendance at a meeting.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/meetings/attendance/remove.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct RemoveAttendance;
+
+impl RemoveAttendance {
+    /// Remove a user's attendance record for a meeting.
+    pub async fn execute(meeting_id: i64, user_id: uuid) -> Result<(), TelescopeError> {
+        send_query::<Self>(remove_attendance::Variables {
+            meeting_id,
+            user_id,
+        })
+        .await
+        .map(|_| ())
+    }
+}
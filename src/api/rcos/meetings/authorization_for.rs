@@ -29,6 +29,8 @@ pub struct UserMeetingAuthorization {
     is_current_coordinator: bool,
     /// Is this user a mentor during an ongoing semester?
     is_current_mentor: bool,
+    /// Does this user host any meetings?
+    is_meeting_host: bool,
 }
 
 impl Default for UserMeetingAuthorization {
@@ -38,6 +40,7 @@ impl Default for UserMeetingAuthorization {
             role: UserRole::External,
             is_current_coordinator: false,
             is_current_mentor: false,
+            is_meeting_host: false,
         }
     }
 }
@@ -50,6 +53,7 @@ impl UserMeetingAuthorization {
             role: UserRole::FacultyAdvisor,
             is_current_mentor: false,
             is_current_coordinator: false,
+            is_meeting_host: false,
         }
     }
 
@@ -111,6 +115,13 @@ impl UserMeetingAuthorization {
         }
     }
 
+    /// Can the user associated with this authorization manage attendance for meetings?
+    /// This is coordinators, faculty advisors, and users who host at least one meeting.
+    /// Access to a specific meeting's attendance is checked with [`Self::can_edit_by_id`].
+    pub fn can_manage_attendance(&self) -> bool {
+        self.can_view_drafts() || (self.user_id.is_some() && self.is_meeting_host)
+    }
+
     /// Can the user associated with this authorization delete meetings?
     /// This is currently just coordinators and faculty advisors.
     pub fn can_delete_meetings(&self) -> bool {
@@ -190,11 +201,15 @@ impl AuthorizationFor {
             .count()
             >= 1;
 
+        // Check if the user hosts any meetings.
+        let is_meeting_host: bool = !api_response.hosted_meetings.is_empty();
+
         return Ok(UserMeetingAuthorization {
             user_id: Some(user_id),
             role: user_role,
             is_current_coordinator,
             is_current_mentor,
+            is_meeting_host,
         });
     }
 }
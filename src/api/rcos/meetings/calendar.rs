@@ -0,0 +1,59 @@
+//! Queries and mutations for the secret tokens of user meeting calendar feeds.
+
+use crate::api::rcos::prelude::*;
+use crate::api::rcos::send_query;
+use crate::error::TelescopeError;
+
+/// Type representing GraphQL query to get a user's calendar feed token.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/meetings/calendar/token.graphql"
+)]
+pub struct CalendarToken;
+
+impl CalendarToken {
+    /// Get the calendar feed token of a user. Return `None` if the user does not exist.
+    pub async fn get(user_id: uuid) -> Result<Option<uuid>, TelescopeError> {
+        send_query::<Self>(calendar_token::Variables { user_id })
+            .await
+            .map(|response| response.users_by_pk.map(|user| user.calendar_token))
+    }
+}
+
+/// Type representing GraphQL query to get the user a calendar feed token belongs to.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/meetings/calendar/token_owner.graphql"
+)]
+pub struct CalendarTokenOwner;
+
+impl CalendarTokenOwner {
+    /// Get the ID of the user with a given calendar feed token, if there is one.
+    pub async fn get(token: uuid) -> Result<Option<uuid>, TelescopeError> {
+        send_query::<Self>(calendar_token_owner::Variables { token })
+            .await
+            .map(|response| response.users.first().map(|user| user.id))
+    }
+}
+
+/// Type representing GraphQL mutation to replace a user's calendar feed token.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/meetings/calendar/reset_token.graphql"
+)]
+pub struct ResetCalendarToken;
+
+impl ResetCalendarToken {
+    /// Give a user a new random calendar feed token. Return the new token.
+    pub async fn execute(user_id: uuid) -> Result<Option<uuid>, TelescopeError> {
+        send_query::<Self>(reset_calendar_token::Variables {
+            user_id,
+            token: ::uuid::Uuid::new_v4(),
+        })
+        .await
+        .map(|response| response.update_users_by_pk.map(|user| user.calendar_token))
+    }
+}
@@ -0,0 +1,132 @@
+//! Query to find scheduling conflicts between meetings.
+
+use crate::api::rcos::meetings::MeetingType;
+use crate::api::rcos::prelude::*;
+use crate::api::rcos::send_query;
+use crate::error::TelescopeError;
+use chrono::{DateTime, Utc};
+
+/// Type representing GraphQL query to get meetings that may conflict with a meeting.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/meetings/conflicts.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct MeetingConflicts;
+
+use self::meeting_conflicts::MeetingConflictsMeetings;
+
+/// A meeting being scheduled, to check for conflicts with other meetings.
+#[derive(Clone, Debug)]
+pub struct ScheduledMeeting {
+    /// The semester the meeting is in.
+    pub semester_id: String,
+    /// The type of the meeting.
+    pub kind: MeetingType,
+    /// The host of the meeting, if there is one.
+    pub host: Option<uuid>,
+    /// The physical location of the meeting, if there is one.
+    pub location: Option<String>,
+    /// The start and end of every meeting in the series. Meetings that do not repeat only
+    /// have one.
+    pub times: Vec<(DateTime<Utc>, DateTime<Utc>)>,
+    /// Meetings to ignore, such as the meeting being edited.
+    pub exclude: Vec<i64>,
+}
+
+/// A meeting that overlaps with a meeting being scheduled.
+#[derive(Clone, Debug, Serialize)]
+pub struct Conflict {
+    /// The conflicting meeting.
+    pub meeting: MeetingConflictsMeetings,
+    /// Why the meetings conflict.
+    pub reasons: Vec<&'static str>,
+}
+
+/// Normalize a location for comparison, so that "DCC 308" and "dcc  308" are the same room.
+fn normalize_location(location: &str) -> String {
+    location
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+impl MeetingConflicts {
+    /// Get the meetings that overlap with a meeting being scheduled and share its host,
+    /// location, or small group.
+    pub async fn get(scheduled: &ScheduledMeeting) -> Result<Vec<Conflict>, TelescopeError> {
+        if scheduled.times.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Query the whole time span of the series at once.
+        let start: DateTime<Utc> = scheduled.times.iter().map(|time| time.0).min().unwrap();
+        let end: DateTime<Utc> = scheduled.times.iter().map(|time| time.1).max().unwrap();
+
+        let location: Option<String> = scheduled
+            .location
+            .as_deref()
+            .map(normalize_location)
+            .filter(|location| !location.is_empty());
+
+        // Use values that match nothing for missing hosts and locations, since null
+        // comparisons match everything. Locations are matched loosely here and exactly below.
+        let meetings: Vec<MeetingConflictsMeetings> =
+            send_query::<Self>(meeting_conflicts::Variables {
+                start,
+                end,
+                semester_id: scheduled.semester_id.clone(),
+                host: scheduled.host.unwrap_or(::uuid::Uuid::nil()),
+                location: location
+                    .as_deref()
+                    .map(|location| location.replace(' ', "%"))
+                    .unwrap_or_default(),
+                exclude: scheduled.exclude.clone(),
+            })
+            .await?
+            .meetings;
+
+        let conflicts = meetings
+            .into_iter()
+            // Only keep meetings that overlap with one of the scheduled meetings.
+            .filter(|meeting| {
+                scheduled.times.iter().any(|(start, end)| {
+                    meeting.start_date_time < *end && *start < meeting.end_date_time
+                })
+            })
+            .filter_map(|meeting| {
+                let mut reasons: Vec<&'static str> = Vec::new();
+
+                let host = meeting.host.as_ref();
+                if scheduled.host.is_some() && host.map(|host| host.id) == scheduled.host {
+                    reasons.push("Same host");
+                }
+
+                let other_location: Option<String> = meeting
+                    .location
+                    .as_deref()
+                    .map(normalize_location)
+                    .filter(|location| !location.is_empty());
+                if location.is_some() && other_location == location {
+                    reasons.push("Same location");
+                }
+
+                let shares_small_group: bool = host
+                    .map(|host| !host.shared_small_groups.is_empty())
+                    .unwrap_or(false);
+                if scheduled.kind == MeetingType::SmallGroup
+                    && meeting.type_ == MeetingType::SmallGroup
+                    && shares_small_group
+                {
+                    reasons.push("Same small group");
+                }
+
+                (!reasons.is_empty()).then(|| Conflict { meeting, reasons })
+            })
+            .collect();
+
+        return Ok(conflicts);
+    }
+}
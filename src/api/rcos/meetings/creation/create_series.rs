@@ -0,0 +1,67 @@
+//! GraphQL mutation to create a series of recurring meetings.
+
+use crate::api::rcos::meetings::creation::create::{create_meeting, normalize_url};
+use crate::api::rcos::prelude::*;
+use crate::api::rcos::send_json_query;
+use crate::error::TelescopeError;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/meetings/creation/create_series.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct CreateMeetingSeries;
+
+use create_meeting_series::{ResponseData, OPERATION_NAME, QUERY};
+
+impl CreateMeetingSeries {
+    /// Create a meeting for each of the given start and end times, with the rest of the
+    /// meeting fields taken from the variables of a single meeting creation (whose times are
+    /// ignored). The meetings share a new series ID. Return the IDs of the created meetings in
+    /// chronological order.
+    pub async fn execute(
+        meeting: create_meeting::Variables,
+        times: Vec<(DateTime<Utc>, DateTime<Utc>)>,
+    ) -> Result<Vec<i64>, TelescopeError> {
+        let series_id: uuid = ::uuid::Uuid::new_v4();
+
+        let meetings: Vec<Value> = times
+            .iter()
+            .map(|(start, end)| {
+                json!({
+                    "series_id": series_id,
+                    "host_user_id": meeting.host,
+                    "title": meeting.title,
+                    "start_date_time": start,
+                    "end_date_time": end,
+                    "description": meeting.description,
+                    "is_draft": meeting.is_draft,
+                    "is_remote": meeting.is_remote,
+                    "location": meeting.location,
+                    // Coerce an empty or whitespace string to none.
+                    "meeting_url": normalize_url(meeting.meeting_url.clone()),
+                    "recording_url": normalize_url(meeting.recording_url.clone()),
+                    "external_presentation_url": normalize_url(meeting.external_slides_url.clone()),
+                    "semester_id": meeting.semester_id,
+                    "type": meeting.kind,
+                })
+            })
+            .collect();
+
+        let response: ResponseData =
+            send_json_query::<Self>(OPERATION_NAME, QUERY, json!({ "meetings": meetings })).await?;
+
+        let mut created = response
+            .insert_meetings
+            .map(|inserted| inserted.returning)
+            .unwrap_or_default();
+        created.sort_by_key(|meeting| meeting.start_date_time);
+        return Ok(created
+            .into_iter()
+            .map(|meeting| meeting.meeting_id)
+            .collect());
+    }
+}
@@ -2,4 +2,5 @@
 
 pub mod context;
 pub mod create;
+pub mod create_series;
 pub mod host_selection;
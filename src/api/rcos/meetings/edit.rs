@@ -1,8 +1,10 @@
 //! Meeting edit mutation and host selection query.
 
 use crate::api::rcos::prelude::*;
-use crate::api::rcos::send_query;
+use crate::api::rcos::{send_json_query, send_query};
 use crate::error::TelescopeError;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
 
 /// Type representing GraphQL meeting edit mutation.
 #[derive(GraphQLQuery)]
@@ -23,6 +25,83 @@ impl EditMeeting {
     }
 }
 
+/// Type representing GraphQL query to get the later meetings of a recurring series.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/meetings/edit/series_meetings.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct SeriesMeetings;
+
+impl SeriesMeetings {
+    /// Get the meetings in a series starting at or after a given time, other than the excluded
+    /// meeting, in chronological order.
+    pub async fn get(
+        series_id: uuid,
+        from: DateTime<Utc>,
+        exclude: i64,
+    ) -> Result<Vec<series_meetings::SeriesMeetingsMeetings>, TelescopeError> {
+        send_query::<Self>(series_meetings::Variables {
+            series_id,
+            from,
+            exclude,
+        })
+        .await
+        .map(|response| response.meetings)
+    }
+}
+
+/// Type representing GraphQL mutation to edit a meeting and the later meetings of its series.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/meetings/edit/edit_series.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct EditMeetingSeries;
+
+impl EditMeetingSeries {
+    /// Apply a meeting edit to a meeting and to the given later meetings of its series, which
+    /// are moved to the given start and end times. Return the ID of the edited meeting if any
+    /// changes were made.
+    pub async fn execute(
+        edit: edit_meeting::Variables,
+        later_meetings: Vec<(i64, DateTime<Utc>, DateTime<Utc>)>,
+    ) -> Result<Option<i64>, TelescopeError> {
+        let meetings: Vec<Value> = later_meetings
+            .iter()
+            .map(|(meeting_id, start, end)| {
+                json!({
+                    "meeting_id": meeting_id,
+                    "title": edit.title,
+                    "start_date_time": start,
+                    "end_date_time": end,
+                    "semester_id": edit.semester_id,
+                    "type": edit.kind,
+                    "description": edit.description,
+                    "is_remote": edit.is_remote,
+                    "is_draft": edit.is_draft,
+                    "meeting_url": edit.meeting_url,
+                    "location": edit.location,
+                    "host_user_id": edit.host,
+                })
+            })
+            .collect();
+
+        let mut variables: Value = json!(edit);
+        variables["meetings"] = json!(meetings);
+
+        send_json_query::<Self>(
+            edit_meeting_series::OPERATION_NAME,
+            edit_meeting_series::QUERY,
+            variables,
+        )
+        .await
+        .map(|response| response.update_meetings_by_pk.map(|obj| obj.meeting_id))
+    }
+}
+
 /// Type representing host selection query used while editing meetings.
 #[derive(GraphQLQuery)]
 #[graphql(
@@ -1,6 +1,9 @@
 //! Queries and mutations to the RCOS API for meeting data.
 
+pub mod attendance;
 pub mod authorization_for;
+pub mod calendar;
+pub mod conflicts;
 pub mod creation;
 pub mod delete;
 pub mod edit;
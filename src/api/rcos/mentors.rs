@@ -0,0 +1,110 @@
+//! Mentor application queries and mutations.
+
+use crate::api::rcos::prelude::*;
+use crate::api::rcos::send_query;
+use crate::error::TelescopeError;
+use chrono::Utc;
+
+/// Type representing GraphQL query to get a user's mentor panel.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/mentors/panel.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct MentorPanel;
+
+impl MentorPanel {
+    /// Get a user's current enrollments with their mentor applications and the small groups they
+    /// are mentoring. Return `None` if the user does not exist.
+    pub async fn get(
+        user_id: uuid,
+    ) -> Result<Option<mentor_panel::MentorPanelUsersByPk>, TelescopeError> {
+        send_query::<Self>(mentor_panel::Variables {
+            user_id,
+            now: Utc::today().naive_utc(),
+        })
+        .await
+        .map(|response| response.users_by_pk)
+    }
+}
+
+/// Type representing GraphQL mutation to submit a mentor application.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/mentors/apply.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct ApplyToMentor;
+
+impl ApplyToMentor {
+    /// Submit a mentor application. If the user already applied this semester, their application
+    /// is replaced and any review of it is cleared.
+    pub async fn execute(
+        semester_id: String,
+        user_id: uuid,
+        reason: String,
+        skillset: String,
+    ) -> Result<(), TelescopeError> {
+        send_query::<Self>(apply_to_mentor::Variables {
+            semester_id,
+            user_id,
+            reason,
+            skillset,
+        })
+        .await
+        .map(|_| ())
+    }
+}
+
+/// Type representing GraphQL query to get the mentor applications for the current semesters.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/mentors/proposals.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct MentorProposals;
+
+impl MentorProposals {
+    /// Get the mentor applications for every current semester.
+    pub async fn get(
+    ) -> Result<Vec<mentor_proposals::MentorProposalsMentorProposals>, TelescopeError> {
+        send_query::<Self>(mentor_proposals::Variables {
+            now: Utc::today().naive_utc(),
+        })
+        .await
+        .map(|response| response.mentor_proposals)
+    }
+}
+
+/// Type representing GraphQL mutation to approve or reject a mentor application.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/mentors/review.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct ReviewMentorProposal;
+
+impl ReviewMentorProposal {
+    /// Record a review of a mentor application. Return `None` if the application does not exist.
+    pub async fn execute(
+        semester_id: String,
+        user_id: uuid,
+        reviewer_id: uuid,
+        is_approved: bool,
+        reviewer_comments: Option<String>,
+    ) -> Result<Option<()>, TelescopeError> {
+        send_query::<Self>(review_mentor_proposal::Variables {
+            semester_id,
+            user_id,
+            reviewer_id,
+            is_approved,
+            reviewer_comments,
+        })
+        .await
+        .map(|response| response.update_mentor_proposals_by_pk.map(|_| ()))
+    }
+}
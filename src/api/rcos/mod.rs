@@ -8,15 +8,23 @@ use graphql_client::{GraphQLQuery, QueryBody, Response as GraphQlResponse};
 use reqwest::{header::HeaderValue, header::ACCEPT, Client};
 use serde_json::Value;
 
+pub mod announcements;
 mod auth;
 pub mod discord_associations;
+pub mod grade_appeals;
 pub mod landing_page_stats;
 pub mod meetings;
+pub mod mentors;
+pub mod pay_requests;
 pub mod prelude;
+pub mod presentations;
 pub mod projects;
 pub mod search_strings;
 pub mod semesters;
+pub mod small_groups;
+pub mod status_updates;
 pub mod users;
+pub mod workshops;
 
 /// The name of this API in error messages.
 const API_NAME: &'static str = "RCOS Central Hasura GraphQL API";
@@ -42,7 +50,23 @@ pub async fn send_query<T: GraphQLQuery>(
     })?;
 
     // Send the query and await the response.
-    let response: Value = send_json_query(operation_name, query, variables).await?;
+    send_json_query::<T>(operation_name, query, variables).await
+}
+
+/// Send a GraphQL query with variables built as a JSON object instead of the macro-generated
+/// variables type. The response is still deserialized into the typed value.
+///
+/// The generated insert input types serialize every unset column (like serial IDs and columns
+/// with defaults) as an explicit null, which the API tries to insert. Mutations that insert
+/// rows build their variables by hand with only the columns they set, and send them using this
+/// function with the operation name and document of the generated module.
+pub async fn send_json_query<T: GraphQLQuery>(
+    operation_name: &str,
+    query_document: &str,
+    variables: Value,
+) -> Result<T::ResponseData, TelescopeError> {
+    // Send the query and await the response.
+    let response: Value = send_raw_query(operation_name, query_document, variables).await?;
 
     // Deserialize the response into the typed value and return.
     serde_json::from_value::<T::ResponseData>(response).map_err(|e| {
@@ -50,11 +74,8 @@ pub async fn send_query<T: GraphQLQuery>(
     })
 }
 
-/// Send an API query using the GraphQL JSON format. This is useful for avoiding issues in the
-/// macro-generated GraphQL types.
-///
-/// The typed version should generally be used instead to avoid runtime type errors.
-pub async fn send_json_query(
+/// Send an API query using the GraphQL JSON format and return the untyped response data.
+async fn send_raw_query(
     query_name: &str,
     query_document: &str,
     variables: Value,
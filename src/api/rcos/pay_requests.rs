@@ -0,0 +1,104 @@
+//! Pay request queries and mutations.
+
+use crate::api::rcos::prelude::*;
+use crate::api::rcos::send_query;
+use crate::error::TelescopeError;
+use chrono::Utc;
+
+/// Type representing GraphQL query to get a user's current enrollments and pay requests.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/pay_requests/for_user.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct UserPayRequests;
+
+impl UserPayRequests {
+    /// Get a user's current enrollments with any pay requests for them.
+    pub async fn get(
+        user_id: uuid,
+    ) -> Result<Vec<user_pay_requests::UserPayRequestsEnrollments>, TelescopeError> {
+        send_query::<Self>(user_pay_requests::Variables {
+            user_id,
+            now: Utc::today().naive_utc(),
+        })
+        .await
+        .map(|response| response.enrollments)
+    }
+}
+
+/// Type representing GraphQL mutation to request pay instead of credit.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/pay_requests/submit.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct SubmitPayRequest;
+
+impl SubmitPayRequest {
+    /// Request pay for a semester. If the user already requested pay this semester, their
+    /// request is replaced and returned to pending review.
+    pub async fn execute(
+        semester_id: String,
+        user_id: uuid,
+        reason: String,
+    ) -> Result<(), TelescopeError> {
+        send_query::<Self>(submit_pay_request::Variables {
+            semester_id,
+            user_id,
+            reason,
+        })
+        .await
+        .map(|_| ())
+    }
+}
+
+/// Type representing GraphQL query to get the pay requests for the current semesters.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/pay_requests/current.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct CurrentPayRequests;
+
+impl CurrentPayRequests {
+    /// Get the pay requests for every current semester.
+    pub async fn get(
+    ) -> Result<Vec<current_pay_requests::CurrentPayRequestsPayRequests>, TelescopeError> {
+        send_query::<Self>(current_pay_requests::Variables {
+            now: Utc::today().naive_utc(),
+        })
+        .await
+        .map(|response| response.pay_requests)
+    }
+}
+
+/// Type representing GraphQL mutation to approve or deny a pay request.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/pay_requests/review.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct ReviewPayRequest;
+
+impl ReviewPayRequest {
+    /// Approve or deny a pay request and set whether the enrollment is for pay to match.
+    /// Return `None` if the request does not exist.
+    pub async fn execute(
+        semester_id: String,
+        user_id: uuid,
+        is_approved: bool,
+    ) -> Result<Option<()>, TelescopeError> {
+        send_query::<Self>(review_pay_request::Variables {
+            semester_id,
+            user_id,
+            is_approved,
+        })
+        .await
+        .map(|response| response.update_pay_requests_by_pk.map(|_| ()))
+    }
+}
@@ -0,0 +1,207 @@
+//! End of semester project presentation grading queries and mutations.
+
+use crate::api::rcos::prelude::*;
+use crate::api::rcos::{send_json_query, send_query};
+use crate::error::TelescopeError;
+use serde_json::Value;
+
+/// Type representing GraphQL query to get the presentation grading session of a semester.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/presentations/session.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct PresentationSession;
+
+impl PresentationSession {
+    /// Get the presentation grading session of a semester, with the projects that have
+    /// students enrolled that semester and their presentations and grades.
+    pub async fn get(
+        semester_id: String,
+    ) -> Result<presentation_session::ResponseData, TelescopeError> {
+        send_query::<Self>(presentation_session::Variables { semester_id }).await
+    }
+}
+
+/// Type representing GraphQL query to check whether a user can grade presentations in a
+/// semester.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/presentations/grader.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct PresentationGrader;
+
+impl PresentationGrader {
+    /// Check if a user is enrolled in a semester. Presentation grades are tied to the
+    /// grader's enrollment.
+    pub async fn is_enrolled(semester_id: String, user_id: uuid) -> Result<bool, TelescopeError> {
+        send_query::<Self>(presentation_grader::Variables {
+            semester_id,
+            user_id,
+        })
+        .await
+        .map(|response| response.enrollment.is_some())
+    }
+}
+
+/// Type representing GraphQL mutation to open or close a presentation grading session.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/presentations/set_open.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct SetPresentationSessionOpen;
+
+impl SetPresentationSessionOpen {
+    /// Open or close the presentation grading session of a semester. The session is created
+    /// if it does not exist yet.
+    pub async fn execute(semester_id: String, is_open: bool) -> Result<(), TelescopeError> {
+        send_query::<Self>(set_presentation_session_open::Variables {
+            semester_id,
+            is_open,
+        })
+        .await
+        .map(|_| ())
+    }
+}
+
+/// Type representing GraphQL mutation to open a presentation grading session.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/presentations/open.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct OpenPresentationSession;
+
+impl OpenPresentationSession {
+    /// Open the presentation grading session of a semester. If the session does not exist
+    /// yet, it is created along with the given rubric criteria (as the title, description,
+    /// and maximum score of each). Criteria should only be given for new sessions.
+    pub async fn execute(
+        semester_id: String,
+        criteria: Vec<(String, String, i64)>,
+    ) -> Result<(), TelescopeError> {
+        let objects: Vec<Value> = criteria
+            .iter()
+            .map(|(title, description, max_score)| {
+                json!({
+                    "title": title,
+                    "description": description,
+                    "max_score": max_score,
+                })
+            })
+            .collect();
+
+        send_json_query::<Self>(
+            open_presentation_session::OPERATION_NAME,
+            open_presentation_session::QUERY,
+            json!({
+                "semester_id": semester_id,
+                "criteria": objects,
+            }),
+        )
+        .await
+        .map(|_| ())
+    }
+}
+
+/// Type representing GraphQL mutation to add a criterion to a presentation grading rubric.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/presentations/add_criterion.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct AddRubricCriterion;
+
+impl AddRubricCriterion {
+    /// Add a criterion to the rubric of a semester's presentation grading session.
+    pub async fn execute(
+        semester_id: String,
+        title: String,
+        description: String,
+        max_score: i64,
+    ) -> Result<(), TelescopeError> {
+        send_query::<Self>(add_rubric_criterion::Variables {
+            semester_id,
+            title,
+            description,
+            max_score,
+        })
+        .await
+        .map(|_| ())
+    }
+}
+
+/// Type representing GraphQL mutation to remove a criterion from a presentation grading rubric.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/presentations/remove_criterion.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct RemoveRubricCriterion;
+
+impl RemoveRubricCriterion {
+    /// Remove a criterion from a presentation grading rubric.
+    pub async fn execute(criterion_id: i64) -> Result<(), TelescopeError> {
+        send_query::<Self>(remove_rubric_criterion::Variables { criterion_id })
+            .await
+            .map(|_| ())
+    }
+}
+
+/// Type representing GraphQL mutation to save a grader's scores for a project presentation.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/presentations/grade.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct SavePresentationGrade;
+
+impl SavePresentationGrade {
+    /// Save a grader's score for each rubric criterion of a project presentation, replacing
+    /// any previous scores. The total of the scores is saved as the grader's grade for the
+    /// project.
+    pub async fn execute(
+        semester_id: String,
+        project_id: i64,
+        grader_id: uuid,
+        scores: Vec<(i64, f64)>,
+    ) -> Result<(), TelescopeError> {
+        let grade: f64 = scores.iter().map(|(_, score)| score).sum();
+
+        let objects: Vec<Value> = scores
+            .iter()
+            .map(|(criterion_id, score)| {
+                json!({
+                    "semester_id": semester_id,
+                    "project_id": project_id,
+                    "grader_id": grader_id,
+                    "criterion_id": criterion_id,
+                    "score": score,
+                })
+            })
+            .collect();
+
+        send_json_query::<Self>(
+            save_presentation_grade::OPERATION_NAME,
+            save_presentation_grade::QUERY,
+            json!({
+                "semester_id": semester_id,
+                "project_id": project_id,
+                "grader_id": grader_id,
+                "grade": grade,
+                "scores": objects,
+            }),
+        )
+        .await
+        .map(|_| ())
+    }
+}
@@ -27,6 +27,8 @@ pub struct UserProjectAuthorization {
     is_current_coordinator: bool,
     /// Is this user a mentor during an ongoing semester?
     is_current_mentor: bool,
+    /// The projects this user leads during an ongoing semester.
+    led_projects: Vec<i64>,
 }
 
 impl Default for UserProjectAuthorization {
@@ -36,6 +38,7 @@ impl Default for UserProjectAuthorization {
             role: UserRole::External,
             is_current_coordinator: false,
             is_current_mentor: false,
+            led_projects: Vec::new(),
         }
     }
 }
@@ -48,6 +51,7 @@ impl UserProjectAuthorization {
             role: UserRole::FacultyAdvisor,
             is_current_mentor: false,
             is_current_coordinator: false,
+            led_projects: Vec::new(),
         }
     }
 
@@ -63,11 +67,10 @@ impl UserProjectAuthorization {
         true
     }
 
-    /// Can the user associated with this authorization edit projects with a given type
-    /// and optionally specified host user ID?
-    pub fn can_edit(&self) -> bool { //TODO allow the creator of the project to modify
-            // of the viewer is a coordinator or faculty advisor
-            self.is_staff()
+    /// Can the user associated with this authorization edit a given project?
+    /// This is coordinators, faculty advisors, and the project's current leads.
+    pub fn can_edit(&self, project_id: i64) -> bool {
+        self.is_staff() || self.led_projects.contains(&project_id)
     }
 
 
@@ -138,11 +141,22 @@ impl AuthorizationFor {
             .count()
             >= 1;
 
+        let led_projects: Vec<i64> = api_response
+            // Flatten the current enrollments where this user is a project lead.
+            .current_semesters
+            .iter()
+            .map(|semester| semester.enrollments.as_slice())
+            .flatten()
+            .filter(|enrollment| enrollment.is_project_lead)
+            .filter_map(|enrollment| enrollment.project_id)
+            .collect();
+
         return Ok(UserProjectAuthorization {
             user_id: Some(user_id),
             role: user_role,
             is_current_coordinator,
             is_current_mentor,
+            led_projects,
         });
     }
 }
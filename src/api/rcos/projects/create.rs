@@ -1,43 +1,77 @@
 //! GraphQL mutation to create a project.
 
 use crate::api::rcos::prelude::*;
-use crate::api::rcos::send_query;
+use crate::api::rcos::send_json_query;
 use crate::error::TelescopeError;
-use chrono::{DateTime, Utc};
-use url::Url;
+use serde_json::Value;
 
 #[derive(GraphQLQuery)]
 #[graphql(
     schema_path = "graphql/rcos/schema.json",
     query_path = "graphql/rcos/projects/create.graphql",
-    response_derives = "Debug,Copy,Clone,Serialize"
+    response_derives = "Debug,Clone,Serialize"
 )]
 pub struct CreateProject;
 
+use create_project::{OPERATION_NAME, QUERY};
+
+/// The approval of a pitch for a new project, recorded when the project is created.
+#[derive(Clone, Debug)]
+pub struct PitchApproval {
+    /// The semester of the pitch.
+    pub semester_id: String,
+    /// The user who pitched the project.
+    pub user_id: uuid,
+    /// The coordinator approving the pitch.
+    pub reviewer_id: uuid,
+    /// The reviewer's comments on the pitch.
+    pub reviewer_comments: Option<String>,
+}
+
 /// Trim the whitespace off a string. If the trimmed string is empty default to None.
 pub fn normalize_url(url: Option<String>) -> Option<String> {
     url.and_then(|string| (!string.trim().is_empty()).then(|| string))
 }
 impl CreateProject {
-    /// Execute a Project creation mutation. Return the created Project's ID.
+    /// Execute a Project creation mutation. If the project was pitched, the pitch's approval is
+    /// recorded in the same mutation. Return the created Project's ID.
     pub async fn execute(
         title: Option<String>,
-        stack: Option<Vec<String>>,
-        repository_urls: Option<Vec<String>>,
+        stack: Vec<String>,
+        repositories: Vec<String>,
         homepage_url: Option<String>,
         description: Option<String>,
         cover_image_url: Option<String>,
-
+        pitch: Option<PitchApproval>,
     ) -> Result<Option<i64>, TelescopeError> {
-        send_query::<Self>(create_project::Variables {
-            title,
-            stack,
-            repository_urls,
-            homepage_url,
-            description,
-            cover_image_url,
-        })
-        .await
-        .map(|response| response.insert_projects_one.map(|obj| obj.project_id))
+        // The project ID of each nested row is filled in from the created project.
+        let variables: Value = json!({
+            "title": title,
+            "homepage_url": homepage_url,
+            "description": description,
+            "cover_image_url": cover_image_url,
+            "stack": stack
+                .iter()
+                .map(|language| json!({ "language": language }))
+                .collect::<Vec<Value>>(),
+            "repositories": repositories
+                .iter()
+                .map(|url| json!({ "url": url }))
+                .collect::<Vec<Value>>(),
+            "pitches": pitch
+                .iter()
+                .map(|pitch| json!({
+                    "semester_id": pitch.semester_id,
+                    "user_id": pitch.user_id,
+                    "reviewer_id": pitch.reviewer_id,
+                    "is_approved": true,
+                    "reviewer_comments": pitch.reviewer_comments,
+                }))
+                .collect::<Vec<Value>>(),
+        });
+
+        send_json_query::<Self>(OPERATION_NAME, QUERY, variables)
+            .await
+            .map(|response| response.insert_projects_one.map(|obj| obj.project_id))
     }
 }
@@ -0,0 +1,50 @@
+//! GraphQL mutation to edit a project.
+
+use crate::api::rcos::prelude::*;
+use crate::api::rcos::send_json_query;
+use crate::error::TelescopeError;
+use serde_json::Value;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/projects/edit.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct EditProject;
+
+use edit_project::{OPERATION_NAME, QUERY};
+
+impl EditProject {
+    /// Execute a project edit mutation. The project's stack and repositories are replaced
+    /// with the given lists. Return the ID of the edited project if it exists.
+    pub async fn execute(
+        project_id: i64,
+        title: String,
+        description: String,
+        homepage_url: Option<String>,
+        cover_image_url: Option<String>,
+        stack: Vec<String>,
+        repositories: Vec<String>,
+    ) -> Result<Option<i64>, TelescopeError> {
+        let variables: Value = json!({
+            "project_id": project_id,
+            "title": title,
+            "description": description,
+            "homepage_url": homepage_url,
+            "cover_image_url": cover_image_url,
+            "stack": stack
+                .iter()
+                .map(|language| json!({ "project_id": project_id, "language": language }))
+                .collect::<Vec<Value>>(),
+            "repositories": repositories
+                .iter()
+                .map(|url| json!({ "project_id": project_id, "url": url }))
+                .collect::<Vec<Value>>(),
+        });
+
+        send_json_query::<Self>(OPERATION_NAME, QUERY, variables)
+            .await
+            .map(|response| response.update_projects_by_pk.map(|obj| obj.project_id))
+    }
+}
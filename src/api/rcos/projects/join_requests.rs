@@ -0,0 +1,145 @@
+//! Project membership queries and join request mutations.
+
+use crate::api::rcos::prelude::*;
+use crate::api::rcos::send_query;
+use crate::error::TelescopeError;
+use chrono::Utc;
+
+/// Type representing GraphQL query to get the members of a project by semester.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/projects/join_requests/members.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct ProjectMembers;
+
+impl ProjectMembers {
+    /// Get the semesters a project had members in, with the members (leads first).
+    pub async fn get(
+        project_id: i64,
+    ) -> Result<Vec<project_members::ProjectMembersSemesters>, TelescopeError> {
+        send_query::<Self>(project_members::Variables { project_id })
+            .await
+            .map(|response| response.semesters)
+    }
+}
+
+/// Type representing GraphQL query to get the current semesters with a user's enrollment
+/// and the pending requests to join a project.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/projects/join_requests/context.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct JoinRequestContext;
+
+impl JoinRequestContext {
+    /// Get the current semesters (most recent first) with the user's enrollment and the
+    /// pending requests to join a project.
+    pub async fn get(
+        project_id: i64,
+        user_id: uuid,
+    ) -> Result<Vec<join_request_context::JoinRequestContextSemesters>, TelescopeError> {
+        send_query::<Self>(join_request_context::Variables {
+            project_id,
+            user_id,
+            now: Utc::today().naive_utc(),
+        })
+        .await
+        .map(|response| response.semesters)
+    }
+
+    /// Get the semester a user would request to join a project for, along with their enrollment
+    /// in it. This is the most recent current semester the user is enrolled in.
+    pub fn join_semester(
+        semesters: &[join_request_context::JoinRequestContextSemesters],
+    ) -> Option<(
+        &join_request_context::JoinRequestContextSemesters,
+        &join_request_context::JoinRequestContextSemestersEnrollment,
+    )> {
+        semesters
+            .iter()
+            .find_map(|semester| semester.enrollment.first().map(|e| (semester, e)))
+    }
+}
+
+/// Type representing GraphQL mutation to request to join a project.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/projects/join_requests/request.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct RequestToJoin;
+
+impl RequestToJoin {
+    /// Request to join a project for a semester the user is enrolled in.
+    pub async fn execute(
+        semester_id: String,
+        user_id: uuid,
+        project_id: i64,
+    ) -> Result<(), TelescopeError> {
+        send_query::<Self>(request_to_join::Variables {
+            semester_id,
+            user_id,
+            project_id,
+        })
+        .await
+        .map(|_| ())
+    }
+}
+
+/// Type representing GraphQL mutation to approve a request to join a project.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/projects/join_requests/approve.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct ApproveJoinRequest;
+
+impl ApproveJoinRequest {
+    /// Add the requesting user to the project for the semester and remove their pending
+    /// requests. Return `None` if the user is not enrolled in the semester.
+    pub async fn execute(
+        semester_id: String,
+        user_id: uuid,
+        project_id: i64,
+    ) -> Result<Option<()>, TelescopeError> {
+        send_query::<Self>(approve_join_request::Variables {
+            semester_id,
+            user_id,
+            project_id,
+        })
+        .await
+        .map(|response| response.update_enrollments_by_pk.map(|_| ()))
+    }
+}
+
+/// Type representing GraphQL mutation to deny a request to join a project.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/projects/join_requests/deny.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct DenyJoinRequest;
+
+impl DenyJoinRequest {
+    /// Remove a request to join a project. Return `None` if there was no such request.
+    pub async fn execute(
+        semester_id: String,
+        user_id: uuid,
+        project_id: i64,
+    ) -> Result<Option<()>, TelescopeError> {
+        send_query::<Self>(deny_join_request::Variables {
+            semester_id,
+            user_id,
+            project_id,
+        })
+        .await
+        .map(|response| response.delete_project_join_requests_by_pk.map(|_| ()))
+    }
+}
@@ -3,4 +3,8 @@
 pub mod projects_page;
 pub mod get_by_id;
 pub mod authorization_for;
-// pub mod create;
+pub mod create;
+pub mod pitches;
+pub mod edit;
+pub mod join_requests;
+pub mod presentations;
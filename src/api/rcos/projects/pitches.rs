@@ -0,0 +1,108 @@
+//! Project pitch queries and mutations.
+
+use crate::api::rcos::prelude::*;
+use crate::api::rcos::send_query;
+use crate::error::TelescopeError;
+use chrono::Utc;
+
+/// Type representing GraphQL query to get the context needed for a user to pitch a project.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/projects/pitches/context.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct PitchContext;
+
+impl PitchContext {
+    /// Get the current semesters a user is enrolled in, their pitches for those semesters, and
+    /// the list of existing projects.
+    pub async fn get(user_id: uuid) -> Result<pitch_context::ResponseData, TelescopeError> {
+        send_query::<Self>(pitch_context::Variables {
+            user_id,
+            now: Utc::today().naive_utc(),
+        })
+        .await
+    }
+}
+
+/// Type representing GraphQL mutation to submit (or resubmit) a project pitch.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/projects/pitches/submit.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct SubmitPitch;
+
+impl SubmitPitch {
+    /// Submit a project pitch. If the user already pitched a project this semester, their pitch
+    /// is replaced and any review of it is cleared.
+    pub async fn execute(vars: submit_pitch::Variables) -> Result<(), TelescopeError> {
+        send_query::<Self>(vars).await.map(|_| ())
+    }
+}
+
+/// Type representing GraphQL query to get the project pitches for a semester.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/projects/pitches/queue.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct PitchQueue;
+
+impl PitchQueue {
+    /// Get the project pitches for a semester. Return `None` if the semester does not exist.
+    pub async fn get(
+        semester_id: String,
+    ) -> Result<Option<pitch_queue::PitchQueueSemester>, TelescopeError> {
+        send_query::<Self>(pitch_queue::Variables { semester_id })
+            .await
+            .map(|response| response.semester)
+    }
+}
+
+/// Type representing GraphQL query to get a single project pitch.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/projects/pitches/get.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct GetPitch;
+
+impl GetPitch {
+    /// Get a user's project pitch for a semester. Return `None` if there is no such pitch.
+    pub async fn get(
+        semester_id: String,
+        user_id: uuid,
+    ) -> Result<Option<get_pitch::GetPitchPitch>, TelescopeError> {
+        send_query::<Self>(get_pitch::Variables {
+            semester_id,
+            user_id,
+        })
+        .await
+        .map(|response| response.pitch)
+    }
+}
+
+/// Type representing GraphQL mutation to approve or reject a project pitch.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/projects/pitches/review.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct ReviewPitch;
+
+impl ReviewPitch {
+    /// Record a review of a project pitch. Return `None` if the pitch does not exist.
+    pub async fn execute(
+        vars: review_pitch::Variables,
+    ) -> Result<Option<review_pitch::ReviewPitchUpdateProjectPitchesByPk>, TelescopeError> {
+        send_query::<Self>(vars)
+            .await
+            .map(|response| response.update_project_pitches_by_pk)
+    }
+}
@@ -0,0 +1,57 @@
+//! Project presentation queries and mutations.
+
+use crate::api::rcos::prelude::*;
+use crate::api::rcos::send_query;
+use crate::error::TelescopeError;
+use chrono::Utc;
+
+/// Type representing GraphQL query to get the presentations of a project.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/projects/presentations/context.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct ProjectPresentations;
+
+impl ProjectPresentations {
+    /// Get the presentations of a project (most recent first) and the current semesters
+    /// the project has members in.
+    pub async fn get(
+        project_id: i64,
+    ) -> Result<project_presentations::ResponseData, TelescopeError> {
+        send_query::<Self>(project_presentations::Variables {
+            project_id,
+            now: Utc::today().naive_utc(),
+        })
+        .await
+    }
+}
+
+/// Type representing GraphQL mutation to submit a project presentation.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/projects/presentations/submit.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct SubmitProjectPresentation;
+
+impl SubmitProjectPresentation {
+    /// Submit the presentation of a project for a semester, replacing any previous one.
+    pub async fn execute(
+        project_id: i64,
+        semester_id: String,
+        presentation_url: String,
+        is_draft: bool,
+    ) -> Result<(), TelescopeError> {
+        send_query::<Self>(submit_project_presentation::Variables {
+            project_id,
+            semester_id,
+            presentation_url,
+            is_draft,
+        })
+        .await
+        .map(|_| ())
+    }
+}
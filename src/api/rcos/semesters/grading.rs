@@ -0,0 +1,102 @@
+//! GraphQL queries and mutations to propose and set the grades of a semester.
+
+use crate::api::rcos::prelude::*;
+use crate::api::rcos::{send_json_query, send_query};
+use crate::error::TelescopeError;
+use chrono::Utc;
+use serde_json::Value;
+
+/// Type representing GraphQL query to get the grading policy of a semester.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/semesters/grading/policy.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct GradingPolicy;
+
+impl GradingPolicy {
+    /// Get a semester with its grading policy, if the semester exists.
+    pub async fn get(
+        semester_id: String,
+    ) -> Result<Option<grading_policy::GradingPolicySemester>, TelescopeError> {
+        send_query::<Self>(grading_policy::Variables { semester_id })
+            .await
+            .map(|response| response.semester)
+    }
+}
+
+/// Type representing GraphQL mutation to save the grading policy of a semester.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/semesters/grading/save_policy.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct SaveGradingPolicy;
+
+impl SaveGradingPolicy {
+    /// Create or replace the grading policy of a semester.
+    pub async fn execute(vars: save_grading_policy::Variables) -> Result<(), TelescopeError> {
+        send_query::<Self>(vars).await.map(|_| ())
+    }
+}
+
+/// Type representing GraphQL query to get the attendance, status updates, and presentation
+/// grades of a semester.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/semesters/grading/data.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct GradingData;
+
+impl GradingData {
+    /// Get the enrollments of a semester along with everything used to propose their grades.
+    pub async fn get(semester_id: String) -> Result<grading_data::ResponseData, TelescopeError> {
+        send_query::<Self>(grading_data::Variables {
+            semester_id,
+            now: Utc::now(),
+        })
+        .await
+    }
+}
+
+/// Type representing GraphQL mutation to set the grades of a semester's enrollments.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/semesters/grading/set_grades.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct SetSemesterGrades;
+
+impl SetSemesterGrades {
+    /// Set the mid-year and final grades of the given enrollments in a semester, as the user
+    /// ID, mid-year grade, and final grade of each.
+    pub async fn execute(
+        semester_id: String,
+        grades: Vec<(uuid, Option<f64>, Option<f64>)>,
+    ) -> Result<(), TelescopeError> {
+        let objects: Vec<Value> = grades
+            .iter()
+            .map(|(user_id, mid_year_grade, final_grade)| {
+                json!({
+                    "semester_id": semester_id,
+                    "user_id": user_id,
+                    "mid_year_grade": mid_year_grade,
+                    "final_grade": final_grade,
+                })
+            })
+            .collect();
+
+        send_json_query::<Self>(
+            set_semester_grades::OPERATION_NAME,
+            set_semester_grades::QUERY,
+            json!({ "grades": objects }),
+        )
+        .await
+        .map(|_| ())
+    }
+}
@@ -3,4 +3,5 @@
 pub mod current;
 pub mod get;
 pub mod get_by_id;
+pub mod grading;
 pub mod mutations;
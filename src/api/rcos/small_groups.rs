@@ -0,0 +1,375 @@
+//! Small group queries and mutations.
+
+use crate::api::rcos::prelude::*;
+use crate::api::rcos::{send_json_query, send_query};
+use crate::error::TelescopeError;
+use chrono::NaiveDate;
+use serde_json::Value;
+use std::collections::HashSet;
+
+/// Type representing GraphQL query to get the small groups of a semester.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/small_groups/semester.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct SemesterSmallGroups;
+
+impl SemesterSmallGroups {
+    /// Get the small groups of a semester, along with the projects and students that can be
+    /// assigned to them.
+    pub async fn get(
+        semester_id: String,
+    ) -> Result<semester_small_groups::ResponseData, TelescopeError> {
+        send_query::<Self>(semester_small_groups::Variables { semester_id }).await
+    }
+}
+
+/// Type representing GraphQL query to get a small group by ID.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/small_groups/get_by_id.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct SmallGroup;
+
+impl SmallGroup {
+    /// Get a small group by ID, if it exists.
+    pub async fn get(
+        small_group_id: i64,
+    ) -> Result<Option<small_group::SmallGroupSmallGroup>, TelescopeError> {
+        send_query::<Self>(small_group::Variables { small_group_id })
+            .await
+            .map(|response| response.small_group)
+    }
+}
+
+/// Type representing GraphQL mutation to create a small group.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/small_groups/create.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct CreateSmallGroup;
+
+impl CreateSmallGroup {
+    /// Create a small group. Return the ID of the created small group.
+    pub async fn execute(
+        semester_id: String,
+        title: String,
+        location: Option<String>,
+    ) -> Result<Option<i64>, TelescopeError> {
+        send_query::<Self>(create_small_group::Variables {
+            semester_id,
+            title,
+            location,
+        })
+        .await
+        .map(|response| {
+            response
+                .insert_small_groups_one
+                .map(|obj| obj.small_group_id)
+        })
+    }
+}
+
+/// Type representing GraphQL mutation to change the title and location of a small group.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/small_groups/edit.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct EditSmallGroup;
+
+impl EditSmallGroup {
+    /// Change the title and location of a small group. Return the small group's semester ID if
+    /// it exists.
+    pub async fn execute(
+        small_group_id: i64,
+        title: String,
+        location: Option<String>,
+    ) -> Result<Option<String>, TelescopeError> {
+        send_query::<Self>(edit_small_group::Variables {
+            small_group_id,
+            title,
+            location,
+        })
+        .await
+        .map(|response| {
+            response
+                .update_small_groups_by_pk
+                .map(|obj| obj.semester_id)
+        })
+    }
+}
+
+/// Type representing GraphQL mutation to delete a small group.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/small_groups/delete.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct DeleteSmallGroup;
+
+impl DeleteSmallGroup {
+    /// Delete a small group and remove its projects and mentors. Return the small group's
+    /// semester ID if it existed.
+    pub async fn execute(small_group_id: i64) -> Result<Option<String>, TelescopeError> {
+        send_query::<Self>(delete_small_group::Variables { small_group_id })
+            .await
+            .map(|response| {
+                response
+                    .delete_small_groups_by_pk
+                    .map(|obj| obj.semester_id)
+            })
+    }
+}
+
+/// Type representing GraphQL mutation to add a project to a small group.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/small_groups/add_project.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct AddSmallGroupProject;
+
+impl AddSmallGroupProject {
+    /// Add a project to a small group. Adding a project that is already in the small group
+    /// does nothing.
+    pub async fn execute(small_group_id: i64, project_id: i64) -> Result<(), TelescopeError> {
+        send_query::<Self>(add_small_group_project::Variables {
+            small_group_id,
+            project_id,
+        })
+        .await
+        .map(|_| ())
+    }
+}
+
+/// Type representing GraphQL mutation to remove a project from a small group.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/small_groups/remove_project.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct RemoveSmallGroupProject;
+
+impl RemoveSmallGroupProject {
+    /// Remove a project from a small group.
+    pub async fn execute(small_group_id: i64, project_id: i64) -> Result<(), TelescopeError> {
+        send_query::<Self>(remove_small_group_project::Variables {
+            small_group_id,
+            project_id,
+        })
+        .await
+        .map(|_| ())
+    }
+}
+
+/// Type representing GraphQL mutation to add a mentor to a small group.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/small_groups/add_mentor.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct AddSmallGroupMentor;
+
+impl AddSmallGroupMentor {
+    /// Add a mentor to a small group. Adding a mentor that is already in the small group does
+    /// nothing.
+    pub async fn execute(small_group_id: i64, user_id: uuid) -> Result<(), TelescopeError> {
+        send_query::<Self>(add_small_group_mentor::Variables {
+            small_group_id,
+            user_id,
+        })
+        .await
+        .map(|_| ())
+    }
+}
+
+/// Type representing GraphQL mutation to remove a mentor from a small group.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/small_groups/remove_mentor.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct RemoveSmallGroupMentor;
+
+impl RemoveSmallGroupMentor {
+    /// Remove a mentor from a small group.
+    pub async fn execute(small_group_id: i64, user_id: uuid) -> Result<(), TelescopeError> {
+        send_query::<Self>(remove_small_group_mentor::Variables {
+            small_group_id,
+            user_id,
+        })
+        .await
+        .map(|_| ())
+    }
+}
+
+/// Type representing GraphQL query to get the small groups of the previous semester.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/small_groups/previous.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct PreviousSmallGroups;
+
+impl PreviousSmallGroups {
+    /// Get the most recent semester with small groups that started before a date, along with
+    /// its small groups.
+    pub async fn get(
+        before: NaiveDate,
+    ) -> Result<Option<previous_small_groups::PreviousSmallGroupsSemesters>, TelescopeError> {
+        send_query::<Self>(previous_small_groups::Variables { before })
+            .await
+            .map(|response| response.semesters.into_iter().next())
+    }
+}
+
+/// Type representing GraphQL mutation to create small groups with their projects and mentors.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/small_groups/clone.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct CloneSmallGroups;
+
+use clone_small_groups::{ResponseData, OPERATION_NAME, QUERY};
+
+impl CloneSmallGroups {
+    /// Copy the small groups of a previous semester into another semester. Projects and
+    /// mentors are only kept if they are enrolled in the new semester, and small groups with a
+    /// title that is already used in the new semester (ignoring case) are skipped. Existing
+    /// titles should be lowercase. Return the number of small groups created.
+    pub async fn execute(
+        semester_id: String,
+        previous: &previous_small_groups::PreviousSmallGroupsSemesters,
+        existing_titles: &HashSet<String>,
+        enrolled_projects: &HashSet<i64>,
+        enrolled: &HashSet<uuid>,
+    ) -> Result<usize, TelescopeError> {
+        let small_groups: Vec<Value> = previous
+            .small_groups
+            .iter()
+            .filter(|small_group| !existing_titles.contains(&small_group.title.to_lowercase()))
+            .map(|small_group| {
+                let projects: Vec<Value> = small_group
+                    .small_group_projects
+                    .iter()
+                    .filter(|project| enrolled_projects.contains(&project.project_id))
+                    .map(|project| json!({ "project_id": project.project_id }))
+                    .collect();
+
+                let mentors: Vec<Value> = small_group
+                    .small_group_mentors
+                    .iter()
+                    .filter(|mentor| enrolled.contains(&mentor.user_id))
+                    .map(|mentor| json!({ "user_id": mentor.user_id }))
+                    .collect();
+
+                json!({
+                    "semester_id": semester_id,
+                    "title": small_group.title,
+                    "location": small_group.location,
+                    "small_group_projects": { "data": projects },
+                    "small_group_mentors": { "data": mentors },
+                })
+            })
+            .collect();
+
+        if small_groups.is_empty() {
+            return Ok(0);
+        }
+
+        let response: ResponseData = send_json_query::<Self>(
+            OPERATION_NAME,
+            QUERY,
+            json!({ "small_groups": small_groups }),
+        )
+        .await?;
+
+        // The affected rows include the inserted projects and mentors, so count the small
+        // groups from the request instead.
+        return Ok(response
+            .insert_small_groups
+            .map(|_| small_groups.len())
+            .unwrap_or(0));
+    }
+}
+
+/// Type representing GraphQL query to get the small groups and projects of a semester, to
+/// balance projects between small groups.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/small_groups/balance.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct SmallGroupBalance;
+
+impl SmallGroupBalance {
+    /// Get the small groups of a semester with their mentor counts, and the projects with
+    /// students enrolled that semester with their member counts.
+    pub async fn get(
+        semester_id: String,
+    ) -> Result<small_group_balance::ResponseData, TelescopeError> {
+        send_query::<Self>(small_group_balance::Variables { semester_id }).await
+    }
+}
+
+/// Type representing GraphQL mutation to replace the small groups of projects in a semester.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/small_groups/assign.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct AssignSmallGroupProjects;
+
+impl AssignSmallGroupProjects {
+    /// Replace the small groups of projects in a semester. Each project in the list is
+    /// removed from its small groups in the semester, then added to the assigned small group
+    /// if there is one.
+    pub async fn execute(
+        semester_id: String,
+        assignments: Vec<(i64, Option<i64>)>,
+    ) -> Result<(), TelescopeError> {
+        let project_ids: Vec<i64> = assignments.iter().map(|(project, _)| *project).collect();
+
+        let objects: Vec<Value> = assignments
+            .iter()
+            .filter_map(|(project_id, small_group_id)| {
+                small_group_id.map(|small_group_id| {
+                    json!({
+                        "project_id": project_id,
+                        "small_group_id": small_group_id,
+                    })
+                })
+            })
+            .collect();
+
+        send_json_query::<Self>(
+            assign_small_group_projects::OPERATION_NAME,
+            assign_small_group_projects::QUERY,
+            json!({
+                "semester_id": semester_id,
+                "project_ids": project_ids,
+                "assignments": objects,
+            }),
+        )
+        .await
+        .map(|_| ())
+    }
+}
@@ -0,0 +1,36 @@
+//! GraphQL mutation to create a status update window.
+
+use crate::api::rcos::{prelude::*, send_query};
+use crate::error::TelescopeError;
+
+/// Type representing GraphQL mutation to create a status update window.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/status_updates/create.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct CreateStatusUpdate;
+
+impl CreateStatusUpdate {
+    /// Create a status update window. Return the created status update's ID.
+    pub async fn execute(
+        semester_id: String,
+        title: Option<String>,
+        open_date_time: timestamptz,
+        close_date_time: timestamptz,
+    ) -> Result<Option<i64>, TelescopeError> {
+        send_query::<Self>(create_status_update::Variables {
+            semester_id,
+            title,
+            open_date_time,
+            close_date_time,
+        })
+        .await
+        .map(|response| {
+            response
+                .insert_status_updates_one
+                .map(|obj| obj.status_update_id)
+        })
+    }
+}
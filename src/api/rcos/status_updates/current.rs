@@ -0,0 +1,28 @@
+//! GraphQL query to get the status updates of the current semesters.
+
+use crate::api::rcos::{prelude::*, send_query};
+use crate::error::TelescopeError;
+use chrono::Utc;
+
+/// Type representing GraphQL query to get the current status updates.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/status_updates/current.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct CurrentStatusUpdates;
+
+impl CurrentStatusUpdates {
+    /// Get the status updates for all current semesters, along with a given user's
+    /// submissions.
+    pub async fn get(
+        user_id: uuid,
+    ) -> Result<current_status_updates::ResponseData, TelescopeError> {
+        send_query::<Self>(current_status_updates::Variables {
+            user_id,
+            now: Utc::today().naive_utc(),
+        })
+        .await
+    }
+}
@@ -0,0 +1,38 @@
+//! GraphQL query to get a status update window by its ID.
+
+use crate::api::rcos::status_updates::is_open;
+use crate::api::rcos::{prelude::*, send_query};
+use crate::error::TelescopeError;
+
+/// Type representing GraphQL query to get a status update and a user's submission.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/status_updates/get_by_id.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct StatusUpdate;
+
+use status_update::StatusUpdateStatusUpdate;
+
+impl StatusUpdate {
+    /// Get a status update window and the given user's submission to it, if any.
+    pub async fn get(
+        status_update_id: i64,
+        user_id: uuid,
+    ) -> Result<Option<StatusUpdateStatusUpdate>, TelescopeError> {
+        send_query::<Self>(status_update::Variables {
+            status_update_id,
+            user_id,
+        })
+        .await
+        .map(|response| response.status_update)
+    }
+}
+
+impl StatusUpdateStatusUpdate {
+    /// Is this status update window currently accepting submissions?
+    pub fn is_open(&self) -> bool {
+        is_open(self.open_date_time, self.close_date_time)
+    }
+}
@@ -0,0 +1,58 @@
+//! GraphQL operations to grade status update submissions.
+
+use crate::api::rcos::{prelude::*, send_query};
+use crate::error::TelescopeError;
+
+/// Type representing GraphQL query to get all submissions to a status update window.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/status_updates/submissions.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct StatusUpdateSubmissions;
+
+impl StatusUpdateSubmissions {
+    /// Get a status update window and all of its submissions.
+    pub async fn get(
+        status_update_id: i64,
+    ) -> Result<
+        Option<status_update_submissions::StatusUpdateSubmissionsStatusUpdate>,
+        TelescopeError,
+    > {
+        send_query::<Self>(status_update_submissions::Variables { status_update_id })
+            .await
+            .map(|response| response.status_update)
+    }
+}
+
+/// Type representing GraphQL mutation to grade a status update submission.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/status_updates/grade.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct GradeStatusUpdate;
+
+impl GradeStatusUpdate {
+    /// Grade a submission. The grade is on a scale from 0 to 1. Return `None` if there was no
+    /// submission to grade.
+    pub async fn execute(
+        status_update_id: i64,
+        user_id: uuid,
+        grader_id: uuid,
+        grade: f64,
+        grader_comments: Option<String>,
+    ) -> Result<Option<()>, TelescopeError> {
+        send_query::<Self>(grade_status_update::Variables {
+            status_update_id,
+            user_id,
+            grader_id,
+            grade,
+            grader_comments,
+        })
+        .await
+        .map(|response| response.update_status_update_submissions_by_pk.map(|_| ()))
+    }
+}
@@ -0,0 +1,17 @@
+//! GraphQL operations on status updates and status update submissions.
+
+pub mod create;
+pub mod current;
+pub mod get_by_id;
+pub mod grading;
+pub mod open;
+pub mod submit;
+
+use chrono::{DateTime, Utc};
+
+/// Is a status update window with the given open and (optional) close times currently
+/// accepting submissions? Windows without a close time stay open indefinitely.
+pub fn is_open(open_date_time: DateTime<Utc>, close_date_time: Option<DateTime<Utc>>) -> bool {
+    let now = Utc::now();
+    open_date_time <= now && close_date_time.map(|close| now <= close).unwrap_or(true)
+}
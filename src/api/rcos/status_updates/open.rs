@@ -0,0 +1,29 @@
+//! GraphQL query to get the currently open status update windows.
+
+use crate::api::rcos::{prelude::*, send_query};
+use crate::error::TelescopeError;
+use chrono::Utc;
+
+/// Type representing GraphQL query to get open status updates.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/status_updates/open.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct OpenStatusUpdates;
+
+impl OpenStatusUpdates {
+    /// Get the currently open status updates (most recently opened first), along with a given
+    /// user's enrollment and submission status for each.
+    pub async fn get(
+        user_id: uuid,
+    ) -> Result<Vec<open_status_updates::OpenStatusUpdatesStatusUpdates>, TelescopeError> {
+        send_query::<Self>(open_status_updates::Variables {
+            user_id,
+            now: Utc::now(),
+        })
+        .await
+        .map(|response| response.status_updates)
+    }
+}
@@ -0,0 +1,35 @@
+//! GraphQL mutation to submit a status update.
+
+use crate::api::rcos::{prelude::*, send_query};
+use crate::error::TelescopeError;
+
+/// Type representing GraphQL mutation to submit (or resubmit) a status update.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/status_updates/submit.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct SubmitStatusUpdate;
+
+impl SubmitStatusUpdate {
+    /// Submit a status update. If the user already submitted to this window, their submission
+    /// is replaced.
+    pub async fn execute(
+        status_update_id: i64,
+        user_id: uuid,
+        this_week: String,
+        next_week: String,
+        blockers: String,
+    ) -> Result<(), TelescopeError> {
+        send_query::<Self>(submit_status_update::Variables {
+            status_update_id,
+            user_id,
+            this_week,
+            next_week,
+            blockers,
+        })
+        .await
+        .map(|_| ())
+    }
+}
@@ -0,0 +1,27 @@
+//! RCOS API query to get the users with linked GitHub accounts.
+
+use crate::api::rcos::prelude::*;
+use crate::api::rcos::send_query;
+use crate::error::TelescopeError;
+
+/// Type representing query for the RCOS users linked to a list of GitHub accounts.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/users/accounts/github_users.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct GitHubUsers;
+
+use git_hub_users::{GitHubUsersUserAccounts, Variables};
+
+impl GitHubUsers {
+    /// Get the RCOS users linked to any of the given GitHub GraphQL node IDs.
+    pub async fn get(
+        github_ids: Vec<String>,
+    ) -> Result<Vec<GitHubUsersUserAccounts>, TelescopeError> {
+        send_query::<Self>(Variables { github_ids })
+            .await
+            .map(|response| response.user_accounts)
+    }
+}
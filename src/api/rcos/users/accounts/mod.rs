@@ -1,6 +1,7 @@
 //! RCOS User account queries and mutations.
 
 pub mod for_user;
+pub mod github_users;
 pub mod link;
 pub mod lookup;
 pub mod reverse_lookup;
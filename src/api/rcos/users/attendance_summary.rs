@@ -0,0 +1,157 @@
+//! Per-semester attendance summary query.
+
+use crate::api::rcos::meetings::MeetingType;
+use crate::api::rcos::{prelude::*, send_query};
+use crate::error::TelescopeError;
+use chrono::Utc;
+use std::collections::HashSet;
+
+/// The meeting types that are tracked in attendance summaries.
+const TRACKED_MEETING_TYPES: [MeetingType; 3] = [
+    MeetingType::LargeGroup,
+    MeetingType::SmallGroup,
+    MeetingType::BonusSession,
+];
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/users/attendance_summary.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct AttendanceSummary;
+
+use attendance_summary::{ResponseData, Variables};
+
+/// A user's attendance at one type of meeting during a semester.
+#[derive(Clone, Debug, Serialize)]
+pub struct MeetingTypeAttendance {
+    /// The type of meeting.
+    pub kind: MeetingType,
+    /// The number of meetings of this type the user attended.
+    pub attended: usize,
+    /// The number of meetings of this type the user was expected to attend so far.
+    /// Bonus sessions are never required.
+    pub required: Option<usize>,
+    /// The percentage of required meetings attended, if there were any.
+    pub percentage: Option<u32>,
+}
+
+/// A user's attendance during a semester they were enrolled in.
+#[derive(Clone, Debug, Serialize)]
+pub struct SemesterAttendance {
+    /// The semester's ID.
+    pub semester_id: String,
+    /// The semester's title.
+    pub title: String,
+    /// Attendance by meeting type.
+    pub by_type: Vec<MeetingTypeAttendance>,
+    /// The number of bonus attendance credits given directly (not via a bonus session).
+    pub bonus_attendances: usize,
+    /// The overall percentage of required meetings attended, with bonus sessions and bonus
+    /// attendances making up for missed meetings. This is capped at 100.
+    pub percentage: Option<u32>,
+}
+
+/// Get a percentage (capped at 100) of a required count, or `None` if nothing is required.
+fn percentage(attended: usize, required: usize) -> Option<u32> {
+    (required > 0).then(|| (attended.min(required) * 100 / required) as u32)
+}
+
+impl AttendanceSummary {
+    /// Get the attendance summary of a user for every semester they were enrolled in, most
+    /// recent first.
+    pub async fn get(user_id: uuid) -> Result<Vec<SemesterAttendance>, TelescopeError> {
+        let response: ResponseData = send_query::<Self>(Variables {
+            user_id,
+            now: Utc::now(),
+            types: TRACKED_MEETING_TYPES.to_vec(),
+        })
+        .await?;
+
+        let mut summaries = Vec::with_capacity(response.enrollments.len());
+        for enrollment in response.enrollments.iter() {
+            let semester = &enrollment.semester;
+
+            // The mentors of the user's small group this semester. Small group meetings are
+            // only required if they are hosted by one of these mentors.
+            let mentors: HashSet<uuid> = enrollment
+                .project
+                .iter()
+                .flat_map(|project| project.small_group_projects.iter())
+                .map(|small_group_project| &small_group_project.small_group)
+                .filter(|small_group| small_group.semester_id == semester.semester_id)
+                .flat_map(|small_group| small_group.small_group_mentors.iter())
+                .map(|mentor| mentor.user_id)
+                .collect();
+
+            let mut by_type = Vec::with_capacity(TRACKED_MEETING_TYPES.len());
+            for kind in TRACKED_MEETING_TYPES.iter() {
+                // The meetings of this type the user was expected to attend. Bonus sessions are
+                // never required.
+                let required_meetings: Option<HashSet<i64>> = match kind {
+                    MeetingType::BonusSession => None,
+                    _ => Some(
+                        semester
+                            .meetings
+                            .iter()
+                            .filter(|meeting| meeting.type_ == *kind)
+                            // Small group meetings are only required if they are hosted by one
+                            // of the user's mentors.
+                            .filter(|meeting| {
+                                *kind != MeetingType::SmallGroup
+                                    || meeting
+                                        .host_user_id
+                                        .map(|host| mentors.contains(&host))
+                                        .unwrap_or(false)
+                            })
+                            .map(|meeting| meeting.meeting_id)
+                            .collect(),
+                    ),
+                };
+
+                // Count the meetings of this type that the user attended this semester. Only
+                // attendances at required meetings count towards required meeting types.
+                let attended: usize = response
+                    .meeting_attendances
+                    .iter()
+                    .filter(|attendance| {
+                        attendance.meeting.semester_id == semester.semester_id
+                            && attendance.meeting.type_ == *kind
+                    })
+                    .filter(|attendance| {
+                        required_meetings
+                            .as_ref()
+                            .map(|required| required.contains(&attendance.meeting.meeting_id))
+                            .unwrap_or(true)
+                    })
+                    .count();
+
+                let required: Option<usize> = required_meetings.as_ref().map(HashSet::len);
+
+                by_type.push(MeetingTypeAttendance {
+                    kind: *kind,
+                    attended,
+                    required,
+                    percentage: required.and_then(|required| percentage(attended, required)),
+                });
+            }
+
+            // Total up the overall attendance.
+            let bonus_attendances: usize = semester.bonus_attendances.len();
+            let total_attended: usize =
+                by_type.iter().map(|a| a.attended).sum::<usize>() + bonus_attendances;
+            let total_required: usize = by_type.iter().filter_map(|a| a.required).sum();
+
+            summaries.push(SemesterAttendance {
+                semester_id: semester.semester_id.clone(),
+                title: semester.title.clone(),
+                by_type,
+                bonus_attendances,
+                percentage: percentage(total_attended, total_required),
+            });
+        }
+
+        return Ok(summaries);
+    }
+}
@@ -44,6 +44,7 @@ impl SaveProfileEdits {
         last_name: String,
         cohort: Option<i64>,
         role: UserRole,
+        timezone: String,
     ) -> Result<Option<uuid>, TelescopeError> {
         send_query::<Self>(save_profile_edits::Variables {
             user_id,
@@ -51,6 +52,7 @@ impl SaveProfileEdits {
             lname: last_name,
             cohort,
             role,
+            timezone,
         })
         .await
         .map(|response| response.update_users_by_pk.map(|obj| obj.id))
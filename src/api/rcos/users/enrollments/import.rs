@@ -0,0 +1,100 @@
+//! GraphQL operations to import enrollments in bulk.
+
+use crate::api::rcos::prelude::*;
+use crate::api::rcos::users::{UserAccountType, UserRole};
+use crate::api::rcos::{send_json_query, send_query};
+use crate::error::TelescopeError;
+use serde_json::Value;
+
+/// Type representing GraphQL query to get the users with a list of RCS IDs and their
+/// enrollments in a semester.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/users/enrollments/import_context.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct EnrollmentImportContext;
+
+impl EnrollmentImportContext {
+    /// Get the users with these RCS IDs, and their enrollments in a semester.
+    pub async fn get(
+        semester_id: String,
+        rcs_ids: Vec<String>,
+    ) -> Result<enrollment_import_context::ResponseData, TelescopeError> {
+        send_query::<Self>(enrollment_import_context::Variables {
+            semester_id,
+            rcs_ids,
+        })
+        .await
+    }
+}
+
+/// Type representing GraphQL mutation to import enrollments.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/users/enrollments/import.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct ImportEnrollments;
+
+/// A placeholder user to create for an RCS ID that does not belong to any user yet. Students
+/// take over their placeholder user when they sign in with their RCS ID.
+#[derive(Clone, Debug)]
+pub struct Placeholder {
+    pub rcs_id: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub credits: i64,
+}
+
+impl ImportEnrollments {
+    /// Set the credits of enrollments in a semester, creating the enrollments that do not exist
+    /// yet. Then create placeholder users enrolled in the semester. Both happen in one
+    /// transaction.
+    pub async fn execute(
+        semester_id: String,
+        enrollments: Vec<(uuid, i64)>,
+        placeholders: Vec<Placeholder>,
+    ) -> Result<(), TelescopeError> {
+        let enrollments: Vec<Value> = enrollments
+            .iter()
+            .map(|(user_id, credits)| {
+                json!({
+                    "semester_id": semester_id,
+                    "user_id": user_id,
+                    "credits": credits,
+                })
+            })
+            .collect();
+
+        let placeholders: Vec<Value> = placeholders
+            .iter()
+            .map(|placeholder| {
+                json!({
+                    "first_name": placeholder.first_name,
+                    "last_name": placeholder.last_name,
+                    "role": UserRole::Student,
+                    "user_accounts": {
+                        "data": [{ "type": UserAccountType::Rpi, "account_id": placeholder.rcs_id }]
+                    },
+                    "enrollments": {
+                        "data": [{ "semester_id": semester_id, "credits": placeholder.credits }]
+                    },
+                })
+            })
+            .collect();
+
+        send_json_query::<Self>(
+            import_enrollments::OPERATION_NAME,
+            import_enrollments::QUERY,
+            json!({
+                "enrollments": enrollments,
+                "placeholders": placeholders,
+            }),
+        )
+        .await
+        .map(|_| ())
+    }
+}
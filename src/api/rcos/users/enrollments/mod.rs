@@ -4,3 +4,4 @@ pub mod enrollments_lookup;
 pub mod user_enrollment_lookup;
 pub mod enrollment_by_ids;
 pub mod edit_enrollment;
+pub mod import;
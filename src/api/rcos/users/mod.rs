@@ -1,6 +1,7 @@
 //! API interactions for RCOS users from the central RCOS API.
 
 pub mod accounts;
+pub mod attendance_summary;
 pub mod create;
 pub mod delete;
 pub mod developers_page;
@@ -10,6 +11,7 @@ pub mod enrollments;
 pub mod navbar_auth;
 pub mod profile;
 pub mod role_lookup;
+pub mod timezone;
 
 /// The valid user roles for all users in the RCOS database.
 #[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Hash, Display)]
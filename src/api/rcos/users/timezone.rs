@@ -0,0 +1,28 @@
+//! Query for a user's timezone preference.
+
+use crate::api::rcos::prelude::*;
+use crate::api::rcos::send_query;
+use crate::error::TelescopeError;
+use chrono_tz::Tz;
+
+/// Type representing GraphQL query to get a user's timezone.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/users/timezone.graphql"
+)]
+pub struct UserTimezone;
+
+impl UserTimezone {
+    /// Get the timezone a user wants times shown in. Return `None` if the user does not exist
+    /// or their timezone is not in the timezone database.
+    pub async fn get(user_id: uuid) -> Result<Option<Tz>, TelescopeError> {
+        send_query::<Self>(user_timezone::Variables { user_id })
+            .await
+            .map(|response| {
+                response
+                    .users_by_pk
+                    .and_then(|user| user.timezone.parse::<Tz>().ok())
+            })
+    }
+}
@@ -0,0 +1,143 @@
+//! Workshop proposal queries and mutations.
+
+use crate::api::rcos::prelude::*;
+use crate::api::rcos::send_query;
+use crate::error::TelescopeError;
+use chrono::Utc;
+
+/// Type representing GraphQL query to get the context needed to propose workshops.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/workshops/context.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct WorkshopContext;
+
+impl WorkshopContext {
+    /// Get a user's current enrollments and previous workshop proposals. Return `None` if the
+    /// user does not exist.
+    pub async fn get(
+        user_id: uuid,
+    ) -> Result<Option<workshop_context::WorkshopContextUsersByPk>, TelescopeError> {
+        send_query::<Self>(workshop_context::Variables {
+            user_id,
+            now: Utc::today().naive_utc(),
+        })
+        .await
+        .map(|response| response.users_by_pk)
+    }
+}
+
+/// Type representing GraphQL mutation to propose a workshop.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/workshops/propose.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct ProposeWorkshop;
+
+impl ProposeWorkshop {
+    /// Propose a workshop. Return the ID of the created proposal.
+    pub async fn execute(vars: propose_workshop::Variables) -> Result<Option<i64>, TelescopeError> {
+        send_query::<Self>(vars).await.map(|response| {
+            response
+                .insert_workshop_proposals_one
+                .map(|obj| obj.workshop_proposal_id)
+        })
+    }
+}
+
+/// Type representing GraphQL query to get the workshop proposals for the current semesters.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/workshops/proposals.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct WorkshopProposals;
+
+impl WorkshopProposals {
+    /// Get the workshop proposals for every current semester.
+    pub async fn get(
+    ) -> Result<Vec<workshop_proposals::WorkshopProposalsWorkshopProposals>, TelescopeError> {
+        send_query::<Self>(workshop_proposals::Variables {
+            now: Utc::today().naive_utc(),
+        })
+        .await
+        .map(|response| response.workshop_proposals)
+    }
+}
+
+/// Type representing GraphQL query to get a workshop proposal by ID.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/workshops/get_by_id.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct WorkshopProposal;
+
+impl WorkshopProposal {
+    /// Get a workshop proposal by ID. Return `None` if there is no such proposal.
+    pub async fn get(
+        workshop_proposal_id: i64,
+    ) -> Result<Option<workshop_proposal::WorkshopProposalWorkshopProposalsByPk>, TelescopeError>
+    {
+        send_query::<Self>(workshop_proposal::Variables {
+            workshop_proposal_id,
+        })
+        .await
+        .map(|response| response.workshop_proposals_by_pk)
+    }
+}
+
+/// Type representing GraphQL mutation to approve or reject a workshop proposal.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/workshops/review.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct ReviewWorkshopProposal;
+
+impl ReviewWorkshopProposal {
+    /// Record a review of a workshop proposal. Return `None` if the proposal does not exist.
+    pub async fn execute(
+        workshop_proposal_id: i64,
+        reviewer_id: uuid,
+        is_approved: bool,
+        reviewer_comments: Option<String>,
+    ) -> Result<Option<()>, TelescopeError> {
+        send_query::<Self>(review_workshop_proposal::Variables {
+            workshop_proposal_id,
+            reviewer_id,
+            is_approved,
+            reviewer_comments,
+        })
+        .await
+        .map(|response| response.update_workshop_proposals_by_pk.map(|_| ()))
+    }
+}
+
+/// Type representing GraphQL mutation to approve a workshop proposal and schedule it.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/rcos/schema.json",
+    query_path = "graphql/rcos/workshops/approve.graphql",
+    response_derives = "Debug,Clone,Serialize"
+)]
+pub struct ApproveWorkshopProposal;
+
+impl ApproveWorkshopProposal {
+    /// Record the approval of a workshop proposal and create its bonus session meeting. Return
+    /// `None` if the proposal does not exist.
+    pub async fn execute(
+        vars: approve_workshop_proposal::Variables,
+    ) -> Result<Option<()>, TelescopeError> {
+        send_query::<Self>(vars)
+            .await
+            .map(|response| response.update_workshop_proposals_by_pk.map(|_| ()))
+    }
+}
@@ -16,18 +16,17 @@ use crate::discord_bot::commands::InteractionResult;
 use crate::env::global_config;
 use serenity::builder::{CreateApplicationCommand, CreateApplicationCommandOption, CreateEmbed};
 use serenity::client::Context;
+use serenity::model::application::command::CommandOptionType;
+use serenity::model::application::interaction::application_command::{
+    ApplicationCommandInteraction, CommandDataOptionValue,
+};
+use serenity::model::application::interaction::{InteractionResponseType, MessageFlags};
 use serenity::model::channel::ChannelType as SerenityChannelType;
 use serenity::model::guild::Role;
 use serenity::model::id::ChannelId;
 use serenity::model::id::{GuildId, RoleId};
-use serenity::model::interactions::application_command::{
-    ApplicationCommandInteraction, ApplicationCommandInteractionDataOptionValue,
-};
-use serenity::model::interactions::{
-    application_command::ApplicationCommandOptionType, InteractionResponseType,
-};
 use serenity::model::permissions::Permissions;
-use serenity::model::prelude::InteractionApplicationCommandCallbackDataFlags;
+use serenity::model::Timestamp;
 use serenity::utils::Color;
 use serenity::Result as SerenityResult;
 
@@ -62,8 +61,8 @@ async fn interaction_error(
                         // Do not allow any mentions
                         .allowed_mentions(|am| am.empty_parse())
                         // Use the ephemeral flag to mark the response as only visible to the user who invoked it.
-                        .flags(InteractionApplicationCommandCallbackDataFlags::EPHEMERAL)
-                        .create_embed(|embed| {
+                        .flags(MessageFlags::EPHEMERAL)
+                        .embed(|embed| {
                             // Add common attributes
                             embed_common(embed)
                                 .color(ERROR_COLOR)
@@ -92,8 +91,8 @@ async fn interaction_success(
                         // Do not allow any mentions
                         .allowed_mentions(|am| am.empty_parse())
                         // Use the ephemeral flag to mark the response as only visible to the user who invoked it.
-                        .flags(InteractionApplicationCommandCallbackDataFlags::EPHEMERAL)
-                        .create_embed(|embed| {
+                        .flags(MessageFlags::EPHEMERAL)
+                        .embed(|embed| {
                             // Add common attributes
                             embed_common(embed).title("OK").description(description)
                         })
@@ -127,15 +126,15 @@ pub fn associate_option<'a>(
     match option {
         _ if option == SUBCOMMAND_GROUP[0] => obj
             .name(option)
-            .kind(ApplicationCommandOptionType::SubCommandGroup)
+            .kind(CommandOptionType::SubCommandGroup)
             .description("associate channel to project and/or small group."),
         _ if option == SUBCOMMAND_GROUP[1] => obj
             .name(option)
-            .kind(ApplicationCommandOptionType::SubCommandGroup)
+            .kind(CommandOptionType::SubCommandGroup)
             .description("associate role to project and/or small group."),
         _ if option == SUBCOMMAND_GROUP[2] => obj
             .name(option)
-            .kind(ApplicationCommandOptionType::SubCommandGroup)
+            .kind(CommandOptionType::SubCommandGroup)
             .description("associate category to project and/or small group."),
         _ => obj,
     }
@@ -150,38 +149,38 @@ pub fn associate_sub_option<'a>(
         _ if option == SUBCOMMAND[0] => match subcommand_group {
             _ if subcommand_group == SUBCOMMAND_GROUP[0] => obj
                 .name(option)
-                .kind(ApplicationCommandOptionType::SubCommand)
+                .kind(CommandOptionType::SubCommand)
                 .description("Associate to project")
                 .add_sub_option(
                     CreateApplicationCommandOption { 0: HashMap::new() }
                         .name("channel_name")
                         .description("Assoicated channel.")
-                        .kind(ApplicationCommandOptionType::Channel)
+                        .kind(CommandOptionType::Channel)
                         .clone(),
                 )
                 .add_sub_option(
                     CreateApplicationCommandOption { 0: HashMap::new() }
                         .name("project_id")
                         .description("associate existing channel with projects.")
-                        .kind(ApplicationCommandOptionType::Integer)
+                        .kind(CommandOptionType::Integer)
                         .clone(),
                 ),
             _ if subcommand_group == SUBCOMMAND_GROUP[1] => obj
                 .name(option)
-                .kind(ApplicationCommandOptionType::SubCommand)
+                .kind(CommandOptionType::SubCommand)
                 .description("Associate to project")
                 .add_sub_option(
                     CreateApplicationCommandOption { 0: HashMap::new() }
                         .name("role_name")
                         .description("Associated role name.")
-                        .kind(ApplicationCommandOptionType::Role)
+                        .kind(CommandOptionType::Role)
                         .clone(),
                 )
                 .add_sub_option(
                     CreateApplicationCommandOption { 0: HashMap::new() }
                         .name("project_id")
                         .description("Project to be associated with.")
-                        .kind(ApplicationCommandOptionType::Integer)
+                        .kind(CommandOptionType::Integer)
                         .clone(),
                 ),
             _ => obj,
@@ -189,56 +188,56 @@ pub fn associate_sub_option<'a>(
         _ if option == SUBCOMMAND[1] => match subcommand_group {
             _ if subcommand_group == SUBCOMMAND_GROUP[0] => obj
                 .name(option)
-                .kind(ApplicationCommandOptionType::SubCommand)
+                .kind(CommandOptionType::SubCommand)
                 .description("Associate to small groups")
                 .add_sub_option(
                     CreateApplicationCommandOption { 0: HashMap::new() }
                         .name("channel_name")
                         .description("Assoicated channel.")
-                        .kind(ApplicationCommandOptionType::Channel)
+                        .kind(CommandOptionType::Channel)
                         .clone(),
                 )
                 .add_sub_option(
                     CreateApplicationCommandOption { 0: HashMap::new() }
                         .name("small_group_id")
                         .description("associate existing channel with small groups.")
-                        .kind(ApplicationCommandOptionType::Integer)
+                        .kind(CommandOptionType::Integer)
                         .clone(),
                 ),
             _ if subcommand_group == SUBCOMMAND_GROUP[1] => obj
                 .name(option)
-                .kind(ApplicationCommandOptionType::SubCommand)
+                .kind(CommandOptionType::SubCommand)
                 .description("Associate to small groups")
                 .add_sub_option(
                     CreateApplicationCommandOption { 0: HashMap::new() }
                         .name("role_name")
                         .description("role name to associate to small groups.")
-                        .kind(ApplicationCommandOptionType::Role)
+                        .kind(CommandOptionType::Role)
                         .clone(),
                 )
                 .add_sub_option(
                     CreateApplicationCommandOption { 0: HashMap::new() }
                         .name("small_group_id")
                         .description("small group to be asscoiated.")
-                        .kind(ApplicationCommandOptionType::Integer)
+                        .kind(CommandOptionType::Integer)
                         .clone(),
                 ),
             _ if subcommand_group == SUBCOMMAND_GROUP[2] => obj
                 .name(option)
-                .kind(ApplicationCommandOptionType::SubCommand)
+                .kind(CommandOptionType::SubCommand)
                 .description("Associate to small groups")
                 .add_sub_option(
                     CreateApplicationCommandOption { 0: HashMap::new() }
                         .name("category_name")
                         .description("Assoicated category.")
-                        .kind(ApplicationCommandOptionType::Channel)
+                        .kind(CommandOptionType::Channel)
                         .clone(),
                 )
                 .add_sub_option(
                     CreateApplicationCommandOption { 0: HashMap::new() }
                         .name("small_group_id")
                         .description("associate existing category with small groups.")
-                        .kind(ApplicationCommandOptionType::Integer)
+                        .kind(CommandOptionType::Integer)
                         .clone(),
                 ),
             _ => obj,
@@ -326,8 +325,8 @@ async fn handle(ctx: &Context, interaction: &ApplicationCommandInteraction) -> S
                             // Do not allow any mentions
                             .allowed_mentions(|am| am.empty_parse())
                             // Use the ephemeral flag to mark the response as only visible to the user who invoked it.
-                            .flags(InteractionApplicationCommandCallbackDataFlags::EPHEMERAL)
-                            .create_embed(|embed| {
+                            .flags(MessageFlags::EPHEMERAL)
+                            .embed(|embed| {
                                 // Add common attributes
                                 embed_common(embed)
                                     .color(ERROR_COLOR)
@@ -393,8 +392,8 @@ async fn handle(ctx: &Context, interaction: &ApplicationCommandInteraction) -> S
                                 // Do not allow any mentions
                                 .allowed_mentions(|am| am.empty_parse())
                                 // Use the ephemeral flag to mark the response as only visible to the user who invoked it.
-                                .flags(InteractionApplicationCommandCallbackDataFlags::EPHEMERAL)
-                                .create_embed(|embed| {
+                                .flags(MessageFlags::EPHEMERAL)
+                                .embed(|embed| {
                                     // Add common attributes
                                     embed_common(embed)
                                         .color(ERROR_COLOR)
@@ -414,7 +413,7 @@ async fn handle(ctx: &Context, interaction: &ApplicationCommandInteraction) -> S
 fn embed_common(create_embed: &mut CreateEmbed) -> &mut CreateEmbed {
     create_embed
         // Timestamp is always now
-        .timestamp(&chrono::Utc::now())
+        .timestamp(Timestamp::now())
         // Footer is telescope version
         .footer(|create_footer| {
             create_footer.text(format!("Telescope {}", env!("CARGO_PKG_VERSION")))
@@ -451,7 +450,7 @@ pub async fn handle_associate_channel(
     let channel_id: ChannelId;
     let channel_kind: ChannelType;
     match interaction_data_option_value {
-        ApplicationCommandInteractionDataOptionValue::Channel(partial_channel) => {
+        CommandDataOptionValue::Channel(partial_channel) => {
             channel_id = partial_channel.id;
             if partial_channel.kind == SerenityChannelType::Text {
                 channel_kind = ChannelType::DiscordText;
@@ -665,7 +664,7 @@ pub async fn handle_associate_role(
 
     let role_id: RoleId;
     match interaction_data_option_value {
-        ApplicationCommandInteractionDataOptionValue::Role(role) => {
+        CommandDataOptionValue::Role(role) => {
             role_id = role.id;
         }
         _ => {
@@ -857,7 +856,7 @@ pub async fn handle_associate_category(
         .unwrap();
     let category_id: ChannelId;
     match interaction_data_option_value {
-        ApplicationCommandInteractionDataOptionValue::Channel(partial_channel) => {
+        CommandDataOptionValue::Channel(partial_channel) => {
             category_id = partial_channel.id;
             if partial_channel.kind != SerenityChannelType::Category {
                 return Some(
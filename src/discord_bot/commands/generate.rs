@@ -12,18 +12,17 @@ use crate::discord_bot::commands::InteractionResult;
 use crate::env::global_config;
 use serenity::builder::{CreateApplicationCommand, CreateApplicationCommandOption, CreateEmbed};
 use serenity::client::Context;
+use serenity::model::application::command::CommandOptionType;
+use serenity::model::application::interaction::application_command::ApplicationCommandInteraction;
+use serenity::model::application::interaction::{InteractionResponseType, MessageFlags};
 use serenity::model::channel::{
     ChannelType as SerenityChannelType, PermissionOverwrite, PermissionOverwriteType,
 };
 use serenity::model::guild::Role;
 use serenity::model::id::ChannelId;
 use serenity::model::id::{GuildId, RoleId};
-use serenity::model::interactions::application_command::ApplicationCommandInteraction;
-use serenity::model::interactions::{
-    application_command::ApplicationCommandOptionType, InteractionResponseType,
-};
 use serenity::model::permissions::Permissions;
-use serenity::model::prelude::InteractionApplicationCommandCallbackDataFlags;
+use serenity::model::Timestamp;
 use serenity::utils::Color;
 use serenity::Result as SerenityResult;
 
@@ -50,7 +49,7 @@ fn generate_permission(project_role: Option<RoleId>, roles: Vec<Role>) -> Vec<Pe
         if role.name == "@everyone" {
             overwrite.push(PermissionOverwrite {
                 allow: Permissions::empty(),
-                deny: Permissions::READ_MESSAGES,
+                deny: Permissions::VIEW_CHANNEL,
                 kind: PermissionOverwriteType::Role(role.id),
             })
             // Grant permission for Faculty Advisors, Coordinators and Sysadmins.
@@ -66,7 +65,7 @@ fn generate_permission(project_role: Option<RoleId>, roles: Vec<Role>) -> Vec<Pe
     // If roles for the project have been generated, also grant permission for users who have the roles.
     if let Some(r) = project_role {
         overwrite.push(PermissionOverwrite {
-            allow: Permissions::READ_MESSAGES
+            allow: Permissions::VIEW_CHANNEL
                 | Permissions::SEND_MESSAGES
                 | Permissions::EMBED_LINKS
                 | Permissions::ATTACH_FILES
@@ -97,8 +96,8 @@ async fn interaction_error(
                         // Do not allow any mentions
                         .allowed_mentions(|am| am.empty_parse())
                         // Use the ephemeral flag to mark the response as only visible to the user who invoked it.
-                        .flags(InteractionApplicationCommandCallbackDataFlags::EPHEMERAL)
-                        .create_embed(|embed| {
+                        .flags(MessageFlags::EPHEMERAL)
+                        .embed(|embed| {
                             // Add common attributes
                             embed_common(embed)
                                 .color(ERROR_COLOR)
@@ -127,8 +126,8 @@ async fn interaction_success(
                         // Do not allow any mentions
                         .allowed_mentions(|am| am.empty_parse())
                         // Use the ephemeral flag to mark the response as only visible to the user who invoked it.
-                        .flags(InteractionApplicationCommandCallbackDataFlags::EPHEMERAL)
-                        .create_embed(|embed| {
+                        .flags(MessageFlags::EPHEMERAL)
+                        .embed(|embed| {
                             // Add common attributes
                             embed_common(embed).title("OK").description(description)
                         })
@@ -162,19 +161,19 @@ pub fn generate_option<'a>(
     match option {
         _ if option == OPTION_NAME[0] => obj
             .name(option)
-            .kind(ApplicationCommandOptionType::SubCommand)
+            .kind(CommandOptionType::SubCommand)
             .description("generate channels for projects and/or small groups."),
         _ if option == OPTION_NAME[1] => obj
             .name(option)
-            .kind(ApplicationCommandOptionType::SubCommand)
+            .kind(CommandOptionType::SubCommand)
             .description("generate roles for projects and/or small groups."),
         _ if option == OPTION_NAME[2] => obj
             .name(option)
-            .kind(ApplicationCommandOptionType::SubCommand)
+            .kind(CommandOptionType::SubCommand)
             .description("generate categories for projects and/or small groups."),
         _ if option == OPTION_NAME[3] => obj
             .name(option)
-            .kind(ApplicationCommandOptionType::SubCommand)
+            .kind(CommandOptionType::SubCommand)
             .description("generate all for projects and/or small groups."),
         _ => obj,
     }
@@ -237,8 +236,8 @@ async fn handle(ctx: &Context, interaction: &ApplicationCommandInteraction) -> S
                             // Do not allow any mentions
                             .allowed_mentions(|am| am.empty_parse())
                             // Use the ephemeral flag to mark the response as only visible to the user who invoked it.
-                            .flags(InteractionApplicationCommandCallbackDataFlags::EPHEMERAL)
-                            .create_embed(|embed| {
+                            .flags(MessageFlags::EPHEMERAL)
+                            .embed(|embed| {
                                 // Add common attributes
                                 embed_common(embed)
                                     .color(ERROR_COLOR)
@@ -305,8 +304,8 @@ async fn handle(ctx: &Context, interaction: &ApplicationCommandInteraction) -> S
                                 // Do not allow any mentions
                                 .allowed_mentions(|am| am.empty_parse())
                                 // Use the ephemeral flag to mark the response as only visible to the user who invoked it.
-                                .flags(InteractionApplicationCommandCallbackDataFlags::EPHEMERAL)
-                                .create_embed(|embed| {
+                                .flags(MessageFlags::EPHEMERAL)
+                                .embed(|embed| {
                                     // Add common attributes
                                     embed_common(embed)
                                         .color(ERROR_COLOR)
@@ -326,7 +325,7 @@ async fn handle(ctx: &Context, interaction: &ApplicationCommandInteraction) -> S
 fn embed_common(create_embed: &mut CreateEmbed) -> &mut CreateEmbed {
     create_embed
         // Timestamp is always now
-        .timestamp(&chrono::Utc::now())
+        .timestamp(Timestamp::now())
         // Footer is telescope version
         .footer(|create_footer| {
             create_footer.text(format!("Telescope {}", env!("CARGO_PKG_VERSION")))
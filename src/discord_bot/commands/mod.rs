@@ -4,12 +4,14 @@ use dashmap::DashMap;
 use futures::future::BoxFuture;
 use serenity::builder::CreateApplicationCommand;
 use serenity::client::Context;
+use serenity::model::application::command::Command as ApplicationCommand;
+use serenity::model::application::interaction::application_command::ApplicationCommandInteraction;
+use serenity::model::application::interaction::modal::ModalSubmitInteraction;
 use serenity::model::guild::Guild;
-use serenity::model::interactions::application_command::ApplicationCommand;
-use serenity::model::interactions::application_command::ApplicationCommandInteraction;
 
 mod associate;
 mod generate;
+mod standup;
 mod whois;
 /// Interactions return a boxed future of a serenity result.
 type InteractionResult<'a> = BoxFuture<'a, serenity::Result<()>>;
@@ -19,6 +21,11 @@ type InteractionResult<'a> = BoxFuture<'a, serenity::Result<()>>;
 pub type InteractionHandler =
     for<'a> fn(&'a Context, &'a ApplicationCommandInteraction) -> InteractionResult<'a>;
 
+/// Modal submission handler type. Like interaction handlers, these are references to async
+/// functions that act on context and the submitted modal.
+pub type ModalHandler =
+    for<'a> fn(&'a Context, &'a ModalSubmitInteraction) -> InteractionResult<'a>;
+
 /// Command builder type. These builder function all act on serenity models
 /// and add the necessary info to them for each command.
 type CommandBuilder = fn(&mut CreateApplicationCommand) -> &mut CreateApplicationCommand;
@@ -50,6 +57,19 @@ const COMMANDS: &'static [Command] = &[
         builder: associate::create_associate,
         handler: associate::handle_associate,
     },
+    // /standup
+    Command {
+        name: standup::COMMAND_NAME,
+        builder: standup::create_standup,
+        handler: standup::handle_standup,
+    },
+];
+
+/// Static list of the custom IDs of the modals opened by Telescope's commands, and the
+/// handlers for their submissions.
+const MODALS: &'static [(&'static str, ModalHandler)] = &[
+    // /standup
+    (standup::MODAL_ID, standup::handle_standup_modal),
 ];
 
 // Global command map.
@@ -81,6 +101,14 @@ pub fn get_handler(command_name: &str) -> Option<InteractionHandler> {
         .map(|entry| *entry.value())
 }
 
+/// Get the handler for a modal submission by the modal's custom ID.
+pub fn get_modal_handler(custom_id: &str) -> Option<ModalHandler> {
+    MODALS
+        .iter()
+        .find(|(modal_id, _)| *modal_id == custom_id)
+        .map(|(_, handler)| *handler)
+}
+
 /// Register all telescope slash command for a whitelisted guild.
 pub async fn register_commands_for_guild(ctx: &mut Context, guild: &Guild) -> serenity::Result<()> {
     // Register each command to the whitelisted Guild ID.
@@ -91,7 +119,7 @@ pub async fn register_commands_for_guild(ctx: &mut Context, guild: &Guild) -> se
         // Populate the objects fields using the builder method for this command.
         (cmd.builder)(&mut command_builder);
         // Convert serenity's hashmap to a JSON map.
-        let json_map = serenity::utils::hashmap_to_json_map(command_builder.0);
+        let json_map = serenity::json::hashmap_to_json_map(command_builder.0);
         // And put that map in a JSON value.
         let json_value = serde_json::Value::Object(json_map);
 
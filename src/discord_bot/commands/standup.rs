@@ -0,0 +1,363 @@
+//! Discord slash command to submit a status update.
+//!
+//! `/standup` opens a modal with the status update fields for the currently open window. The
+//! modal's submission is handled separately, since Discord sends it as its own interaction.
+
+use crate::api::rcos::prelude::uuid;
+use crate::api::rcos::status_updates::open::{
+    open_status_updates::OpenStatusUpdatesStatusUpdates, OpenStatusUpdates,
+};
+use crate::api::rcos::status_updates::submit::SubmitStatusUpdate;
+use crate::api::rcos::users::discord_whois::DiscordWhoIs;
+use crate::discord_bot::commands::InteractionResult;
+use crate::env::global_config;
+use serenity::builder::{
+    CreateApplicationCommand, CreateEmbed, CreateInputText, CreateInteractionResponse,
+};
+use serenity::client::Context;
+use serenity::model::application::component::{ActionRowComponent, InputTextStyle};
+use serenity::model::application::interaction::application_command::ApplicationCommandInteraction;
+use serenity::model::application::interaction::modal::ModalSubmitInteraction;
+use serenity::model::application::interaction::{InteractionResponseType, MessageFlags};
+use serenity::model::id::UserId;
+use serenity::model::Timestamp;
+use serenity::utils::Color;
+use serenity::Result as SerenityResult;
+
+/// The name of this slash command.
+pub const COMMAND_NAME: &'static str = "standup";
+
+/// The custom ID of the modal opened by this command.
+pub const MODAL_ID: &'static str = "standup";
+
+/// The custom ID of the modal field for what the user did this week.
+const THIS_WEEK: &'static str = "this_week";
+
+/// The custom ID of the modal field for what the user plans to do next week.
+const NEXT_WEEK: &'static str = "next_week";
+
+/// The custom ID of the modal field for anything blocking the user.
+const BLOCKERS: &'static str = "blockers";
+
+/// The embed color of /standup error responses.
+const ERROR_COLOR: Color = Color::new(0xDC3545); // bootstrap 4 error color
+
+/// The embed color of successful /standup responses.
+const SUCCESS_COLOR: Color = Color::new(0x28A745); // bootstrap 4 success color
+
+/// Modify a builder object to add the info for the /standup command.
+pub fn create_standup(obj: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
+    obj.name(COMMAND_NAME)
+        .description("Submit your status update for the currently open window")
+}
+
+/// Handle a user calling the /standup command from Discord.
+pub fn handle_standup<'a>(
+    ctx: &'a Context,
+    interaction: &'a ApplicationCommandInteraction,
+) -> InteractionResult<'a> {
+    // Wrap the inner async function in a pinned box.
+    return Box::pin(async move { handle(ctx, interaction).await });
+}
+
+/// Handle a user submitting the /standup modal from Discord.
+pub fn handle_standup_modal<'a>(
+    ctx: &'a Context,
+    interaction: &'a ModalSubmitInteraction,
+) -> InteractionResult<'a> {
+    // Wrap the inner async function in a pinned box.
+    return Box::pin(async move { handle_modal(ctx, interaction).await });
+}
+
+/// Build a text field of the /standup modal, filled in with the user's previous answer if
+/// there is one.
+fn input_field<'a>(
+    obj: &'a mut CreateInputText,
+    custom_id: &str,
+    label: &str,
+    previous: Option<&str>,
+    required: bool,
+) -> &'a mut CreateInputText {
+    obj.custom_id(custom_id)
+        .label(label)
+        .style(InputTextStyle::Paragraph)
+        .required(required);
+
+    // Discord rejects empty values.
+    if let Some(previous) = previous.filter(|previous| !previous.is_empty()) {
+        obj.value(previous);
+    }
+    return obj;
+}
+
+/// Build an ephemeral embed response to an interaction.
+fn embed_response<'a, 'b>(
+    create_response: &'b mut CreateInteractionResponse<'a>,
+    color: Color,
+    title: &str,
+    description: String,
+) -> &'b mut CreateInteractionResponse<'a> {
+    create_response
+        .kind(InteractionResponseType::ChannelMessageWithSource)
+        .interaction_response_data(|rdata| {
+            rdata
+                // Do not allow any mentions
+                .allowed_mentions(|am| am.empty_parse())
+                // Use the ephemeral flag to mark the response as only visible to the user who invoked it.
+                .flags(MessageFlags::EPHEMERAL)
+                .embed(|embed| {
+                    embed_common(embed)
+                        .color(color)
+                        .title(title)
+                        .description(description)
+                })
+        })
+}
+
+/// The title and description of an error response.
+type ErrorResponse = (&'static str, String);
+
+/// Find the RCOS user of a Discord user and the status update window they can submit to.
+/// Return the error to respond with if there is none.
+async fn find_window(
+    discord_user: UserId,
+) -> Result<(uuid, OpenStatusUpdatesStatusUpdates), ErrorResponse> {
+    // Resolve the invoking Discord user to an RCOS user.
+    let rcos_user = DiscordWhoIs::send(*discord_user.as_u64())
+        .await
+        .map_err(|err| {
+            error!("Could not query the RCOS API: {}", err);
+            (
+                "RCOS API Error",
+                format!(
+                    "We could not look up your account because the RCOS API responded with \
+                    an error. Please contact a coordinator. Error: {}",
+                    err
+                ),
+            )
+        })?
+        .get_user()
+        .ok_or((
+            "Account Not Linked",
+            format!(
+                "Your Discord account is not linked to an RCOS account. You can link it at {}.",
+                global_config().telescope_url
+            ),
+        ))?;
+
+    // Find the open status update windows.
+    let open_windows = OpenStatusUpdates::get(rcos_user.id).await.map_err(|err| {
+        error!("Could not query the RCOS API: {}", err);
+        (
+            "RCOS API Error",
+            format!(
+                "We could not find the open status update because the RCOS API responded \
+                with an error. Please contact a coordinator. Error: {}",
+                err
+            ),
+        )
+    })?;
+
+    if open_windows.is_empty() {
+        return Err((
+            "No Open Status Update",
+            "There is no status update accepting submissions right now.".into(),
+        ));
+    }
+
+    // Use the most recently opened window for a semester this user is enrolled in.
+    let window = open_windows
+        .iter()
+        .find(|window| !window.semester.enrollments.is_empty())
+        .ok_or((
+            "Not Enrolled",
+            format!(
+                "You are not enrolled in {}, so you cannot submit this status update.",
+                open_windows[0].semester.title
+            ),
+        ))?;
+
+    // Graded submissions cannot be changed.
+    if window
+        .submission
+        .first()
+        .map(|submission| submission.grade.is_some())
+        .unwrap_or(false)
+    {
+        return Err((
+            "Already Graded",
+            "Your submission for this status update has already been graded.".into(),
+        ));
+    }
+
+    return Ok((rcos_user.id, window.clone()));
+}
+
+/// Inner async fn to handle /standup commands without dealing with annoying types. This
+/// opens the modal if the user can submit a status update.
+async fn handle(ctx: &Context, interaction: &ApplicationCommandInteraction) -> SerenityResult<()> {
+    let window = match find_window(interaction.user.id).await {
+        Ok((_, window)) => window,
+        Err((title, description)) => {
+            return interaction
+                .create_interaction_response(&ctx.http, |create_response| {
+                    embed_response(create_response, ERROR_COLOR, title, description)
+                })
+                .await;
+        }
+    };
+
+    // Fill in the user's previous submission if they are editing it.
+    let previous = window.submission.first();
+
+    return interaction
+        .create_interaction_response(&ctx.http, |create_response| {
+            create_response
+                .kind(InteractionResponseType::Modal)
+                .interaction_response_data(|rdata| {
+                    rdata
+                        .custom_id(MODAL_ID)
+                        // Discord limits modal titles to 45 characters.
+                        .title(
+                            window
+                                .title
+                                .as_deref()
+                                .unwrap_or("Status Update")
+                                .chars()
+                                .take(45)
+                                .collect::<String>(),
+                        )
+                        .components(|components| {
+                            components
+                                .create_action_row(|row| {
+                                    row.create_input_text(|input| {
+                                        input_field(
+                                            input,
+                                            THIS_WEEK,
+                                            "What did you do this week?",
+                                            previous
+                                                .map(|submission| submission.this_week.as_str()),
+                                            true,
+                                        )
+                                    })
+                                })
+                                .create_action_row(|row| {
+                                    row.create_input_text(|input| {
+                                        input_field(
+                                            input,
+                                            NEXT_WEEK,
+                                            "What do you plan to do next week?",
+                                            previous
+                                                .map(|submission| submission.next_week.as_str()),
+                                            true,
+                                        )
+                                    })
+                                })
+                                .create_action_row(|row| {
+                                    row.create_input_text(|input| {
+                                        input_field(
+                                            input,
+                                            BLOCKERS,
+                                            "Is anything blocking you?",
+                                            previous.map(|submission| submission.blockers.as_str()),
+                                            false,
+                                        )
+                                    })
+                                })
+                        })
+                })
+        })
+        .await;
+}
+
+/// Get the trimmed value of a field of a submitted modal by its custom ID.
+fn get_field(interaction: &ModalSubmitInteraction, custom_id: &str) -> String {
+    interaction
+        .data
+        .components
+        .iter()
+        .flat_map(|row| row.components.iter())
+        .find_map(|component| match component {
+            ActionRowComponent::InputText(input) if input.custom_id == custom_id => {
+                Some(input.value.trim().to_string())
+            }
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+/// Inner async fn to handle /standup modal submissions. The window is looked up again since
+/// it may have closed while the modal was open.
+async fn handle_modal(ctx: &Context, interaction: &ModalSubmitInteraction) -> SerenityResult<()> {
+    // Respond to the interaction with an ephemeral embed.
+    let respond = |color: Color, title: &'static str, description: String| {
+        interaction.create_interaction_response(&ctx.http, move |create_response| {
+            embed_response(create_response, color, title, description)
+        })
+    };
+
+    let (user_id, window) = match find_window(interaction.user.id).await {
+        Ok(found) => found,
+        Err((title, description)) => return respond(ERROR_COLOR, title, description).await,
+    };
+
+    // Submit the status update.
+    let status_update_url = format!(
+        "{}/engage/status_updates/{}",
+        global_config().telescope_url,
+        window.status_update_id
+    );
+    let result = SubmitStatusUpdate::execute(
+        window.status_update_id,
+        user_id,
+        get_field(interaction, THIS_WEEK),
+        get_field(interaction, NEXT_WEEK),
+        get_field(interaction, BLOCKERS),
+    )
+    .await;
+
+    match result {
+        Ok(()) => {
+            respond(
+                SUCCESS_COLOR,
+                "Status Update Submitted",
+                format!(
+                    "Your status update was submitted. You can view or edit it at {}.",
+                    status_update_url
+                ),
+            )
+            .await
+        }
+        Err(err) => {
+            error!("Could not submit status update: {}", err);
+            respond(
+                ERROR_COLOR,
+                "RCOS API Error",
+                format!(
+                    "We could not submit your status update because the RCOS API responded \
+                    with an error. You can also submit it at {}. Error: {}",
+                    status_update_url, err
+                ),
+            )
+            .await
+        }
+    }
+}
+
+/// Add common data to a Discord embed. This includes the author, footer, and timestamp.
+fn embed_common(create_embed: &mut CreateEmbed) -> &mut CreateEmbed {
+    create_embed
+        // Timestamp is always now
+        .timestamp(Timestamp::now())
+        // Footer is telescope version
+        .footer(|create_footer| {
+            create_footer.text(format!("Telescope {}", env!("CARGO_PKG_VERSION")))
+        })
+        // Author links to telescope's github.
+        .author(|create_author| {
+            create_author
+                // Don't include the telescope icon - we only link to the github
+                .name("Telescope")
+                .url("https://github.com/rcos/Telescope")
+        })
+}
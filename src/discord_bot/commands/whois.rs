@@ -5,11 +5,10 @@ use crate::discord_bot::commands::InteractionResult;
 use crate::env::global_config;
 use serenity::builder::{CreateApplicationCommand, CreateApplicationCommandOption, CreateEmbed};
 use serenity::client::Context;
-use serenity::model::interactions::application_command::ApplicationCommandInteraction;
-use serenity::model::interactions::{
-    application_command::ApplicationCommandOptionType, InteractionResponseType,
-};
-use serenity::model::prelude::InteractionApplicationCommandCallbackDataFlags;
+use serenity::model::application::command::CommandOptionType;
+use serenity::model::application::interaction::application_command::ApplicationCommandInteraction;
+use serenity::model::application::interaction::{InteractionResponseType, MessageFlags};
+use serenity::model::Timestamp;
 use serenity::utils::Color;
 use serenity::Result as SerenityResult;
 
@@ -25,7 +24,7 @@ pub const ERROR_COLOR: Color = Color::new(0xDC3545); // bootstrap 4 error color
 /// Build the option for the /whois command.
 fn whois_option(obj: &mut CreateApplicationCommandOption) -> &mut CreateApplicationCommandOption {
     obj.name(OPTION_NAME)
-        .kind(ApplicationCommandOptionType::User)
+        .kind(CommandOptionType::User)
         .description("The user to get information about")
         .required(true)
 }
@@ -93,8 +92,8 @@ async fn handle(ctx: &Context, interaction: &ApplicationCommandInteraction) -> S
                             // Do not allow any mentions
                             .allowed_mentions(|am| am.empty_parse())
                             // Use the ephemeral flag to mark the response as only visible to the user who invoked it.
-                            .flags(InteractionApplicationCommandCallbackDataFlags::EPHEMERAL)
-                            .create_embed(|embed| {
+                            .flags(MessageFlags::EPHEMERAL)
+                            .embed(|embed| {
                                 // Add common attributes
                                 embed_common(embed)
                                     .color(ERROR_COLOR)
@@ -125,8 +124,8 @@ async fn handle(ctx: &Context, interaction: &ApplicationCommandInteraction) -> S
                         // Allow no mentions
                         .allowed_mentions(|am| am.empty_parse())
                         // Use the ephemeral flag to hide the response from everyone except the user who invoked it.
-                        .flags(InteractionApplicationCommandCallbackDataFlags::EPHEMERAL)
-                        .create_embed(|create_embed| {
+                        .flags(MessageFlags::EPHEMERAL)
+                        .embed(|create_embed| {
                             // Set common embed fields (author, footer, timestamp)
                             embed_common(create_embed);
 
@@ -163,7 +162,7 @@ async fn handle(ctx: &Context, interaction: &ApplicationCommandInteraction) -> S
 fn embed_common(create_embed: &mut CreateEmbed) -> &mut CreateEmbed {
     create_embed
         // Timestamp is always now
-        .timestamp(&chrono::Utc::now())
+        .timestamp(Timestamp::now())
         // Footer is telescope version
         .footer(|create_footer| {
             create_footer.text(format!("Telescope {}", env!("CARGO_PKG_VERSION")))
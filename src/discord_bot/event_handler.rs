@@ -1,11 +1,14 @@
 //! Event handling code for the telescope Discord Bot.
 
-use crate::discord_bot::commands::{get_handler, register_commands_for_guild, InteractionHandler};
+use crate::discord_bot::commands::{
+    get_handler, get_modal_handler, register_commands_for_guild, InteractionHandler, ModalHandler,
+};
 use crate::env::global_config;
 use serenity::client::{Context, EventHandler};
+use serenity::model::application::interaction::Interaction;
 use serenity::model::gateway::Ready;
 use serenity::model::guild::Guild;
-use serenity::model::interactions::Interaction;
+use tokio_compat_02::FutureExt;
 
 /// ZST representing the event handler for telescope's discord bot.
 pub struct Handler;
@@ -90,8 +93,10 @@ impl EventHandler for Handler {
                     return;
                 }
 
-                // Call the handler on the interaction.
-                let result: serenity::Result<()> = (handler.unwrap())(&ctx, &command).await;
+                // Call the handler on the interaction. Handlers make RCOS API requests, which
+                // need a tokio 0.2 context.
+                let result: serenity::Result<()> =
+                    (handler.unwrap())(&ctx, &command).compat().await;
 
                 // Log any errors from the handler.
                 if let Err(err) = result {
@@ -99,6 +104,33 @@ impl EventHandler for Handler {
                 }
             }
 
+            // Modal submissions. These map to the command that opened the modal.
+            Interaction::ModalSubmit(submission) => {
+                // Clone the modal's custom ID.
+                let modal_id = submission.data.custom_id.clone();
+
+                // Get the modal's handler
+                let handler: Option<ModalHandler> = get_modal_handler(modal_id.as_str());
+
+                // Error if the handler doesn't exist.
+                if handler.is_none() {
+                    error!(
+                        "Handler not found for modal '{}'. Submission: {:#?}",
+                        modal_id, submission
+                    );
+                    return;
+                }
+
+                // Call the handler on the submission.
+                let result: serenity::Result<()> =
+                    (handler.unwrap())(&ctx, &submission).compat().await;
+
+                // Log any errors from the handler.
+                if let Err(err) = result {
+                    error!("Modal '{}' handler returned an error: {}", modal_id, err);
+                }
+            }
+
             // Non-exhaustive match requires other branch.
             other => warn!("Unhandled interaction: {:?}", other),
         }
@@ -3,11 +3,13 @@
 mod commands;
 mod event_handler;
 
+use crate::api::discord::on_discord_runtime;
 use crate::discord_bot::event_handler::Handler;
 use crate::env::{global_config, DiscordConfig};
 use actix::{Actor, ActorContext, ActorFuture, AsyncContext, Context, Supervised};
 use futures::future::LocalBoxFuture;
 use futures::Future;
+use serenity::model::gateway::GatewayIntents;
 use serenity::{Client, Result as SerenityResult};
 use std::pin::Pin;
 use std::task::Context as StdContext;
@@ -30,7 +32,7 @@ impl DiscordBot {
             .expect("Could not parse Discord Application ID.");
 
         // Instantiate a serenity Discord client.
-        return Client::builder(&discord_conf.bot_token)
+        return Client::builder(&discord_conf.bot_token, GatewayIntents::non_privileged())
             .event_handler(Handler)
             .application_id(app_id)
             .await;
@@ -41,10 +43,10 @@ impl DiscordBot {
         Self::create().await?.start_autosharded().await
     }
 
-    /// Run create_and_listen in an Actix compatible future.
+    /// Run create_and_listen on the Discord runtime in an Actix compatible future.
     fn wrapped_create_and_listen() -> ListeningFuture {
         ListeningFuture {
-            inner: Box::pin(Self::create_and_listen()),
+            inner: Box::pin(on_discord_runtime(Self::create_and_listen())),
         }
     }
 }
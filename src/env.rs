@@ -13,6 +13,14 @@ pub struct GithubOauthConfig {
     pub client_id: ClientId,
     /// The GitHub OAuth application client secret.
     pub client_secret: ClientSecret,
+    /// A GitHub access token used to query project repository activity.
+    /// Repository activity is not shown without it.
+    #[serde(default)]
+    pub api_token: Option<String>,
+    /// The GitHub GraphQL API endpoint. This can be overridden to use a local
+    /// stub of the GitHub API. Defaults to <https://api.github.com/graphql>.
+    #[serde(default)]
+    pub api_url: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -29,6 +37,15 @@ pub struct DiscordConfig {
 
     /// The RCOS Discord Guild ID.
     pub rcos_guild_id: String,
+
+    /// The ID of the channel in the RCOS Discord to post announcements in.
+    /// Announcements are not posted to Discord without one.
+    #[serde(default)]
+    pub announcement_channel_id: Option<String>,
+
+    /// The ID of a role to ping when posting announcements.
+    #[serde(default)]
+    pub announcement_role_id: Option<String>,
 }
 
 impl DiscordConfig {
@@ -39,6 +56,21 @@ impl DiscordConfig {
             .parse::<u64>()
             .expect("Malformed RCOS Guild ID")
     }
+
+    /// Get the announcement channel ID as a `u64` if there is one.
+    pub fn announcement_channel_id(&self) -> Option<u64> {
+        self.announcement_channel_id.as_deref().map(|id| {
+            id.parse::<u64>()
+                .expect("Malformed announcement channel ID")
+        })
+    }
+
+    /// Get the announcement role ID as a `u64` if there is one.
+    pub fn announcement_role_id(&self) -> Option<u64> {
+        self.announcement_role_id
+            .as_deref()
+            .map(|id| id.parse::<u64>().expect("Malformed announcement role ID"))
+    }
 }
 
 /// The config of the server instance.
@@ -1,3 +1,6 @@
+use crate::api::rcos::announcements::{
+    active_announcements::ActiveAnnouncementsAnnouncements, ActiveAnnouncements,
+};
 use crate::error::TelescopeError;
 use crate::templates::navbar::Navbar;
 use crate::templates::tags::Tags;
@@ -23,6 +26,9 @@ pub struct Page {
 
     /// Open Graph Protocol tags.
     pub ogp_tags: Tags,
+
+    /// Announcements shown in a banner at the top of the page.
+    pub announcements: Vec<ActiveAnnouncementsAnnouncements>,
 }
 
 impl Page {
@@ -35,12 +41,20 @@ impl Page {
         title: impl Into<String>,
         content: Template,
     ) -> Result<Self, TelescopeError> {
+        // Announcements are not essential to the page, so pages (including error pages) are
+        // still shown without them if they cannot be retrieved.
+        let announcements = ActiveAnnouncements::get().await.unwrap_or_else(|err| {
+            error!("Could not get active announcements: {}", err);
+            Vec::new()
+        });
+
         Ok(Page {
             title: title.into(),
             navbar: Navbar::for_request(request).await?,
             content,
             version: env!("CARGO_PKG_VERSION").to_string(),
             ogp_tags: Tags::for_request(request),
+            announcements,
         })
     }
 
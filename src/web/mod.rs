@@ -1,5 +1,6 @@
 //! Web services and utilities.
 
+use chrono::{DateTime, Local, NaiveDate, NaiveTime, TimeZone, Utc};
 use reqwest::header::HeaderValue;
 
 pub mod csrf;
@@ -16,3 +17,13 @@ pub fn telescope_ua() -> HeaderValue {
     HeaderValue::from_str(TELESCOPE_USER_AGENT.as_str())
         .expect("Could not make Telescope User-Agent")
 }
+
+/// Convert a local date and an "HH:MM" time string from an HTML form to a UTC timestamp.
+pub fn local_timestamp(date: NaiveDate, time: &str) -> Option<DateTime<Utc>> {
+    let time: NaiveTime = format!("{}:00", time).parse::<NaiveTime>().ok()?;
+    Local
+        .from_local_datetime(&date.and_time(time))
+        // Expect that there is only one valid local time for this.
+        .single()
+        .map(|local| local.with_timezone(&Utc))
+}
@@ -0,0 +1,72 @@
+//! Final grade appeal triage services.
+
+use crate::api::rcos::grade_appeals::{GradeAppealQueue, ResolveGradeAppeal};
+use crate::error::TelescopeError;
+use crate::templates::page::Page;
+use crate::templates::Template;
+use actix_web::http::header::LOCATION;
+use actix_web::web::{Form, Path, Query, ServiceConfig};
+use actix_web::{HttpRequest, HttpResponse};
+use uuid::Uuid;
+
+/// The handlebars template for the grade appeal triage queue.
+const TEMPLATE_PATH: &'static str = "admin/grade_appeals";
+
+/// Register grade appeal triage services. These are registered in the admin scope.
+pub fn register(config: &mut ServiceConfig) {
+    config.service(queue).service(resolve);
+}
+
+/// Query parameters for the triage queue.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct QueueQuery {
+    /// Include appeals that were already handled.
+    #[serde(default)]
+    all: bool,
+}
+
+/// Page listing final grade appeals.
+#[get("/grade_appeals")]
+async fn queue(req: HttpRequest, Query(query): Query<QueueQuery>) -> Result<Page, TelescopeError> {
+    let mut template = Template::new(TEMPLATE_PATH);
+    template.fields = json!({
+        "appeals": GradeAppealQueue::get(query.all).await?,
+        "all": query.all,
+    });
+    return template.in_page(&req, "Grade Appeals").await;
+}
+
+/// Form submitted by faculty advisors to resolve an appeal.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct ResolveForm {
+    /// The final grade to record on the enrollment.
+    final_grade: f64,
+}
+
+/// Endpoint to set the final grade of an appealed enrollment and mark the appeal handled.
+#[post("/grade_appeals/{semester_id}/{user_id}/resolve")]
+async fn resolve(
+    Path((semester_id, user_id)): Path<(String, Uuid)>,
+    Form(form): Form<ResolveForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    // Grades are percentages.
+    if !form.final_grade.is_finite() || form.final_grade < 0.0 || form.final_grade > 100.0 {
+        return Err(TelescopeError::BadRequest {
+            header: "Invalid Grade".into(),
+            message: "Final grades must be between 0 and 100.".into(),
+            show_status_code: false,
+        });
+    }
+
+    ResolveGradeAppeal::execute(semester_id, user_id, Some(form.final_grade))
+        .await?
+        .ok_or(TelescopeError::resource_not_found(
+            "Appeal Not Found",
+            "Could not find a grade appeal by this user for this semester.",
+        ))?;
+
+    // Send the faculty advisor back to the queue.
+    return Ok(HttpResponse::Found()
+        .header(LOCATION, "/admin/grade_appeals")
+        .finish());
+}
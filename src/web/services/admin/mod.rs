@@ -1,5 +1,7 @@
 //! Services for the admin panel.
 
+mod grade_appeals;
+mod pay_requests;
 mod semesters;
 
 use crate::api::rcos::users::role_lookup::RoleLookup;
@@ -53,7 +55,11 @@ pub fn register(config: &mut ServiceConfig) {
             // Verify that the viewer has the admin role.
             .wrap(admin_authorization_middleware)
             // Semester services
-            .configure(semesters::register),
+            .configure(semesters::register)
+            // Pay request services
+            .configure(pay_requests::register)
+            // Final grade appeal services
+            .configure(grade_appeals::register),
     );
 }
 
@@ -0,0 +1,66 @@
+//! Pay request review services.
+
+use crate::api::rcos::pay_requests::{CurrentPayRequests, ReviewPayRequest};
+use crate::error::TelescopeError;
+use crate::templates::page::Page;
+use crate::templates::Template;
+use actix_web::http::header::LOCATION;
+use actix_web::web::{Form, Path, ServiceConfig};
+use actix_web::{HttpRequest, HttpResponse};
+use uuid::Uuid;
+
+/// The handlebars template for the pay request review page.
+const TEMPLATE_PATH: &'static str = "admin/pay_requests";
+
+/// Register pay request review services. These are registered in the admin scope.
+pub fn register(config: &mut ServiceConfig) {
+    config.service(index).service(review);
+}
+
+/// Page listing the pay requests for the current semesters.
+#[get("/pay_requests")]
+async fn index(req: HttpRequest) -> Result<Page, TelescopeError> {
+    let mut template = Template::new(TEMPLATE_PATH);
+    template.fields = json!({
+        "pay_requests": CurrentPayRequests::get().await?,
+    });
+    return template.in_page(&req, "Pay Requests").await;
+}
+
+/// Form submitted by admins to approve or deny a pay request.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct ReviewForm {
+    /// Either "approve" or "deny".
+    decision: String,
+}
+
+/// Endpoint to approve or deny a pay request.
+#[post("/pay_requests/{semester_id}/{user_id}/review")]
+async fn review(
+    Path((semester_id, user_id)): Path<(String, Uuid)>,
+    Form(form): Form<ReviewForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    let is_approved: bool = match form.decision.as_str() {
+        "approve" => true,
+        "deny" => false,
+        _ => {
+            return Err(TelescopeError::BadRequest {
+                header: "Invalid Decision".into(),
+                message: "Pay requests can only be approved or denied.".into(),
+                show_status_code: false,
+            })
+        }
+    };
+
+    ReviewPayRequest::execute(semester_id, user_id, is_approved)
+        .await?
+        .ok_or(TelescopeError::resource_not_found(
+            "Pay Request Not Found",
+            "Could not find a pay request by this user for this semester.",
+        ))?;
+
+    // Send the admin back to the review page.
+    return Ok(HttpResponse::Found()
+        .header(LOCATION, "/admin/pay_requests")
+        .finish());
+}
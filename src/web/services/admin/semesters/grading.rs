@@ -0,0 +1,517 @@
+//! Semester grading services.
+//!
+//! Each semester has a grading policy with weights and thresholds. Telescope uses the policy
+//! to propose a mid-year and final grade for every enrollment from attendance, status updates,
+//! and presentation grades. Advisors review and override the proposals before saving them.
+
+use crate::api::rcos::semesters::grading::{
+    grading_data, grading_policy, save_grading_policy, GradingData, GradingPolicy,
+    SaveGradingPolicy, SetSemesterGrades,
+};
+use crate::error::TelescopeError;
+use crate::templates::page::Page;
+use crate::templates::Template;
+use actix_web::http::header::LOCATION;
+use actix_web::web::{Form, Path, ServiceConfig};
+use actix_web::{HttpRequest, HttpResponse};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde_json::Value;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// The handlebars template for the grading policy form.
+const POLICY_TEMPLATE: &'static str = "admin/semesters/forms/grading_policy";
+
+/// The handlebars template to review proposed grades.
+const REVIEW_TEMPLATE: &'static str = "admin/semesters/grading";
+
+/// Register semester grading services.
+pub fn register(config: &mut ServiceConfig) {
+    config
+        .service(edit_policy)
+        .service(submit_policy)
+        .service(review)
+        .service(submit_grades);
+}
+
+/// The weights and thresholds used to propose grades for a semester.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+struct Policy {
+    attendance_weight: f64,
+    status_update_weight: f64,
+    presentation_weight: f64,
+    /// The number of large and small group meetings to attend for full attendance credit.
+    required_attendances: i64,
+    /// The number of bonus attendances that count as attended meetings.
+    max_bonus_attendances: i64,
+    /// The fraction of the semester's status updates to submit for full credit.
+    status_update_threshold: f64,
+}
+
+impl Default for Policy {
+    /// The policy of semesters that have not set one. This matches the database defaults.
+    fn default() -> Self {
+        Policy {
+            attendance_weight: 0.5,
+            status_update_weight: 0.25,
+            presentation_weight: 0.25,
+            required_attendances: 20,
+            max_bonus_attendances: 2,
+            status_update_threshold: 0.8,
+        }
+    }
+}
+
+impl From<&grading_policy::GradingPolicySemesterGradingPolicy> for Policy {
+    fn from(policy: &grading_policy::GradingPolicySemesterGradingPolicy) -> Self {
+        Policy {
+            attendance_weight: policy.attendance_weight,
+            status_update_weight: policy.status_update_weight,
+            presentation_weight: policy.presentation_weight,
+            required_attendances: policy.required_attendances,
+            max_bonus_attendances: policy.max_bonus_attendances,
+            status_update_threshold: policy.status_update_threshold,
+        }
+    }
+}
+
+impl From<&grading_data::GradingDataSemesterGradingPolicy> for Policy {
+    fn from(policy: &grading_data::GradingDataSemesterGradingPolicy) -> Self {
+        Policy {
+            attendance_weight: policy.attendance_weight,
+            status_update_weight: policy.status_update_weight,
+            presentation_weight: policy.presentation_weight,
+            required_attendances: policy.required_attendances,
+            max_bonus_attendances: policy.max_bonus_attendances,
+            status_update_threshold: policy.status_update_threshold,
+        }
+    }
+}
+
+/// Make the form template for a semester's grading policy.
+fn make_policy_form(semester_id: &str, title: &str, policy: &Policy) -> Template {
+    let mut form = Template::new(POLICY_TEMPLATE);
+
+    // Values are strings so that zeros are still filled in by the template.
+    form.fields = json!({
+        "id": semester_id,
+        "title": title,
+        "attendance_weight": {"value": policy.attendance_weight.to_string()},
+        "status_update_weight": {"value": policy.status_update_weight.to_string()},
+        "presentation_weight": {"value": policy.presentation_weight.to_string()},
+        "required_attendances": {"value": policy.required_attendances.to_string()},
+        "max_bonus_attendances": {"value": policy.max_bonus_attendances.to_string()},
+        "status_update_threshold": {"value": policy.status_update_threshold.to_string()},
+    });
+
+    return form;
+}
+
+/// Get a semester with its grading policy, or an error if the semester does not exist.
+async fn get_policy(
+    semester_id: &str,
+) -> Result<grading_policy::GradingPolicySemester, TelescopeError> {
+    GradingPolicy::get(semester_id.to_string())
+        .await?
+        .ok_or(TelescopeError::resource_not_found(
+            "Semester Not Found",
+            "Could not find a semester by this ID.",
+        ))
+}
+
+/// Service to display the grading policy form of a semester.
+#[get("/semesters/grading/{semester_id}/policy")]
+async fn edit_policy(
+    req: HttpRequest,
+    Path(semester_id): Path<String>,
+) -> Result<Page, TelescopeError> {
+    let semester = get_policy(semester_id.as_str()).await?;
+    let policy: Policy = semester
+        .grading_policy
+        .as_ref()
+        .map(Policy::from)
+        .unwrap_or_default();
+
+    make_policy_form(
+        semester.semester_id.as_str(),
+        semester.title.as_str(),
+        &policy,
+    )
+    .in_page(&req, "Grading Policy")
+    .await
+}
+
+/// Service to save the grading policy of a semester.
+#[post("/semesters/grading/{semester_id}/policy")]
+async fn submit_policy(
+    req: HttpRequest,
+    Path(semester_id): Path<String>,
+    Form(policy): Form<Policy>,
+) -> Result<HttpResponse, TelescopeError> {
+    let semester = get_policy(semester_id.as_str()).await?;
+
+    // Find the first issue with the submitted policy, if any.
+    let weights: [(&str, f64); 3] = [
+        ("attendance_weight", policy.attendance_weight),
+        ("status_update_weight", policy.status_update_weight),
+        ("presentation_weight", policy.presentation_weight),
+    ];
+    let negative_weight: Option<&str> = weights
+        .iter()
+        .find(|(_, weight)| !weight.is_finite() || *weight < 0.0)
+        .map(|(field, _)| *field);
+    let threshold: f64 = policy.status_update_threshold;
+    let issue: Option<(&str, &str)> = if let Some(field) = negative_weight {
+        Some((field, "Weights cannot be negative."))
+    } else if weights.iter().map(|(_, weight)| weight).sum::<f64>() <= 0.0 {
+        Some((
+            "attendance_weight",
+            "At least one weight must be above zero.",
+        ))
+    } else if policy.required_attendances < 1 {
+        Some((
+            "required_attendances",
+            "At least one meeting must be required.",
+        ))
+    } else if policy.max_bonus_attendances < 0 {
+        Some((
+            "max_bonus_attendances",
+            "Bonus attendances cannot be negative.",
+        ))
+    } else if !(threshold > 0.0 && threshold <= 1.0) {
+        Some((
+            "status_update_threshold",
+            "The threshold must be above 0 and at most 1.",
+        ))
+    } else {
+        None
+    };
+
+    if let Some((field, message)) = issue {
+        let mut return_form: Template = make_policy_form(
+            semester.semester_id.as_str(),
+            semester.title.as_str(),
+            &policy,
+        );
+        return_form.fields[field]["issue"] = json!(message);
+        let page = return_form.in_page(&req, "Grading Policy").await?;
+        return Err(TelescopeError::InvalidForm(page));
+    }
+
+    SaveGradingPolicy::execute(save_grading_policy::Variables {
+        semester_id: semester.semester_id.clone(),
+        attendance_weight: policy.attendance_weight,
+        status_update_weight: policy.status_update_weight,
+        presentation_weight: policy.presentation_weight,
+        required_attendances: policy.required_attendances,
+        max_bonus_attendances: policy.max_bonus_attendances,
+        status_update_threshold: policy.status_update_threshold,
+    })
+    .await?;
+
+    return Ok(HttpResponse::Found()
+        .header(
+            LOCATION,
+            format!("/admin/semesters/grading/{}", semester.semester_id),
+        )
+        .finish());
+}
+
+/// The scores (from 0 to 1) of an enrollment for each grade component. Components that do not
+/// apply are left out of the grade.
+#[derive(Clone, Debug, Serialize)]
+struct Proposal {
+    /// Attended large and small group meetings.
+    attended: usize,
+    /// Bonus attendances counted as attended meetings.
+    bonus: usize,
+    attendance: f64,
+    status_updates: Option<f64>,
+    presentation: Option<f64>,
+    /// The proposed grade out of 100, if any component applies.
+    grade: Option<f64>,
+}
+
+/// Everything about a semester used to propose grades, indexed by user and project.
+struct SemesterRecords<'a> {
+    policy: Policy,
+    attendances: HashMap<Uuid, Vec<DateTime<Utc>>>,
+    bonus_attendances: HashMap<Uuid, Vec<DateTime<Utc>>>,
+    status_updates: &'a [grading_data::GradingDataStatusUpdates],
+    presentation_grades: HashMap<i64, Vec<f64>>,
+    /// The highest total presentation grade, if the semester has a presentation rubric.
+    max_presentation_grade: Option<f64>,
+}
+
+impl<'a> SemesterRecords<'a> {
+    /// Index the records of a semester.
+    fn new(data: &'a grading_data::ResponseData, policy: Policy) -> Self {
+        let mut attendances: HashMap<Uuid, Vec<DateTime<Utc>>> = HashMap::new();
+        for attendance in data.meeting_attendances.iter() {
+            attendances
+                .entry(attendance.user_id)
+                .or_default()
+                .push(attendance.meeting.start_date_time);
+        }
+
+        let mut bonus_attendances: HashMap<Uuid, Vec<DateTime<Utc>>> = HashMap::new();
+        for bonus in data.bonus_attendances.iter() {
+            bonus_attendances
+                .entry(bonus.user_id)
+                .or_default()
+                .push(bonus.created_at);
+        }
+
+        let mut presentation_grades: HashMap<i64, Vec<f64>> = HashMap::new();
+        for grade in data.project_presentation_grades.iter() {
+            presentation_grades
+                .entry(grade.project_id)
+                .or_default()
+                .push(grade.grade);
+        }
+
+        let max_presentation_grade: Option<f64> = data
+            .semester
+            .as_ref()
+            .and_then(|semester| semester.presentation_grading_session.as_ref())
+            .map(|session| {
+                session
+                    .criteria
+                    .iter()
+                    .map(|criterion| criterion.max_score as f64)
+                    .sum::<f64>()
+            })
+            .filter(|max| *max > 0.0);
+
+        SemesterRecords {
+            policy,
+            attendances,
+            bonus_attendances,
+            status_updates: data.status_updates.as_slice(),
+            presentation_grades,
+            max_presentation_grade,
+        }
+    }
+
+    /// Propose a grade for an enrollment from the records before a cutoff. The mid-year grade
+    /// uses the first half of the semester, needs half of the required attendances, and does
+    /// not include presentations.
+    fn propose(
+        &self,
+        enrollment: &grading_data::GradingDataEnrollments,
+        cutoff: DateTime<Utc>,
+        is_mid_year: bool,
+    ) -> Proposal {
+        let policy: &Policy = &self.policy;
+        let count_before = |records: &HashMap<Uuid, Vec<DateTime<Utc>>>| -> usize {
+            records
+                .get(&enrollment.user_id)
+                .map(|times| times.iter().filter(|time| **time < cutoff).count())
+                .unwrap_or(0)
+        };
+
+        // Attendance, with bonus attendances counted as attended meetings.
+        let attended: usize = count_before(&self.attendances);
+        let bonus: usize =
+            count_before(&self.bonus_attendances).min(policy.max_bonus_attendances as usize);
+        let required: f64 = if is_mid_year {
+            (policy.required_attendances as f64 / 2.0).ceil()
+        } else {
+            policy.required_attendances as f64
+        };
+        let attendance: f64 = ((attended + bonus) as f64 / required).min(1.0);
+
+        // Status updates. Submissions that have not been graded count in full.
+        let status_updates: Vec<&grading_data::GradingDataStatusUpdates> = self
+            .status_updates
+            .iter()
+            .filter(|status_update| status_update.open_date_time < cutoff)
+            .collect();
+        let status_update_score: Option<f64> = (!status_updates.is_empty()).then(|| {
+            let submitted: f64 = status_updates
+                .iter()
+                .filter_map(|status_update| {
+                    status_update
+                        .status_update_submissions
+                        .iter()
+                        .find(|submission| submission.user_id == enrollment.user_id)
+                })
+                .map(|submission| submission.grade.unwrap_or(1.0))
+                .sum();
+            let expected: f64 = status_updates.len() as f64 * policy.status_update_threshold;
+            (submitted / expected).min(1.0)
+        });
+
+        // The mean presentation grade of the enrollment's project. Projects without grades
+        // score zero.
+        let presentation: Option<f64> = if is_mid_year {
+            None
+        } else {
+            self.max_presentation_grade
+                .zip(enrollment.project_id)
+                .map(|(max, project_id)| {
+                    self.presentation_grades
+                        .get(&project_id)
+                        .filter(|grades| !grades.is_empty())
+                        .map(|grades| {
+                            let mean: f64 = grades.iter().sum::<f64>() / grades.len() as f64;
+                            (mean / max).min(1.0)
+                        })
+                        .unwrap_or(0.0)
+                })
+        };
+
+        // Weighted average of the components that apply.
+        let components: [(f64, Option<f64>); 3] = [
+            (policy.attendance_weight, Some(attendance)),
+            (policy.status_update_weight, status_update_score),
+            (policy.presentation_weight, presentation),
+        ];
+        let (weighted, total_weight): (f64, f64) = components
+            .iter()
+            .filter_map(|(weight, score)| score.map(|score| (weight * score, *weight)))
+            .fold((0.0, 0.0), |(a, b), (c, d)| (a + c, b + d));
+        let grade: Option<f64> =
+            (total_weight > 0.0).then(|| (weighted / total_weight * 100.0 * 100.0).round() / 100.0);
+
+        Proposal {
+            attended,
+            bonus,
+            attendance,
+            status_updates: status_update_score,
+            presentation,
+            grade,
+        }
+    }
+}
+
+/// Get the start of a day as a UTC timestamp.
+fn start_of_day(date: NaiveDate) -> DateTime<Utc> {
+    DateTime::from_utc(date.and_hms(0, 0, 0), Utc)
+}
+
+/// Get the records of a semester, or an error if it does not exist.
+async fn get_records(semester_id: &str) -> Result<grading_data::ResponseData, TelescopeError> {
+    let data = GradingData::get(semester_id.to_string()).await?;
+    if data.semester.is_none() {
+        return Err(TelescopeError::resource_not_found(
+            "Semester Not Found",
+            "Could not find a semester by this ID.",
+        ));
+    }
+    return Ok(data);
+}
+
+/// Format a score from 0 to 1 as a percentage.
+fn format_score(score: Option<f64>) -> Value {
+    score
+        .map(|score| json!(format!("{:.0}%", score * 100.0)))
+        .unwrap_or(Value::Null)
+}
+
+/// Page for advisors to review the proposed grades of a semester.
+#[get("/semesters/grading/{semester_id}")]
+async fn review(req: HttpRequest, Path(semester_id): Path<String>) -> Result<Page, TelescopeError> {
+    let data = get_records(semester_id.as_str()).await?;
+    let semester = data.semester.as_ref().unwrap();
+    let policy: Policy = semester
+        .grading_policy
+        .as_ref()
+        .map(Policy::from)
+        .unwrap_or_default();
+    let records = SemesterRecords::new(&data, policy);
+
+    // The mid-year grade covers the first half of the semester. The final grade covers the
+    // whole semester.
+    let start: DateTime<Utc> = start_of_day(semester.start_date);
+    let end: DateTime<Utc> = start_of_day(semester.end_date) + Duration::days(1);
+    let midpoint: DateTime<Utc> = start + (end - start) / 2;
+
+    let rows: Vec<Value> = data
+        .enrollments
+        .iter()
+        .map(|enrollment| {
+            let mid_year: Proposal = records.propose(enrollment, midpoint, true);
+            let final_proposal: Proposal = records.propose(enrollment, end, false);
+            json!({
+                "user": enrollment.user,
+                "has_project": enrollment.project_id.is_some(),
+                "attended": final_proposal.attended,
+                "bonus": final_proposal.bonus,
+                "attendance": format_score(Some(final_proposal.attendance)),
+                "status_updates": format_score(final_proposal.status_updates),
+                "presentation": format_score(final_proposal.presentation),
+                "current_mid_year_grade": enrollment.mid_year_grade,
+                "current_final_grade": enrollment.final_grade,
+                "proposed_mid_year_grade": mid_year.grade.map(|grade| grade.to_string()),
+                "proposed_final_grade": final_proposal.grade.map(|grade| grade.to_string()),
+            })
+        })
+        .collect();
+
+    let mut template = Template::new(REVIEW_TEMPLATE);
+    template.fields = json!({
+        "semester": semester,
+        "policy": policy,
+        "has_policy": semester.grading_policy.is_some(),
+        "has_rubric": records.max_presentation_grade.is_some(),
+        "status_update_count": data.status_updates.len(),
+        "rows": rows,
+    });
+    return template.in_page(&req, "Semester Grades").await;
+}
+
+/// Service to save the reviewed grades of a semester. The form maps `mid_year_{user_id}` and
+/// `final_{user_id}` to the grades of each enrollment. Empty fields leave grades unchanged.
+#[post("/semesters/grading/{semester_id}")]
+async fn submit_grades(
+    Path(semester_id): Path<String>,
+    Form(form): Form<HashMap<String, String>>,
+) -> Result<HttpResponse, TelescopeError> {
+    let data = get_records(semester_id.as_str()).await?;
+
+    // Read a submitted grade. Return the current grade if the field is empty or missing.
+    let read_grade = |field: String, current: Option<f64>| -> Result<Option<f64>, TelescopeError> {
+        match form.get(&field).map(|value| value.trim()) {
+            None | Some("") => Ok(current),
+            Some(value) => value
+                .parse::<f64>()
+                .ok()
+                .filter(|grade| *grade >= 0.0 && *grade <= 100.0)
+                .map(Some)
+                .ok_or(TelescopeError::BadRequest {
+                    header: "Invalid Grade".into(),
+                    message: format!("Grades must be numbers from 0 to 100, not \"{}\".", value),
+                    show_status_code: false,
+                }),
+        }
+    };
+
+    // Only update enrollments with changed grades.
+    let mut grades: Vec<(Uuid, Option<f64>, Option<f64>)> = Vec::new();
+    for enrollment in data.enrollments.iter() {
+        let mid_year_grade: Option<f64> = read_grade(
+            format!("mid_year_{}", enrollment.user_id),
+            enrollment.mid_year_grade,
+        )?;
+        let final_grade: Option<f64> = read_grade(
+            format!("final_{}", enrollment.user_id),
+            enrollment.final_grade,
+        )?;
+
+        if mid_year_grade != enrollment.mid_year_grade || final_grade != enrollment.final_grade {
+            grades.push((enrollment.user_id, mid_year_grade, final_grade));
+        }
+    }
+
+    if !grades.is_empty() {
+        SetSemesterGrades::execute(semester_id.clone(), grades).await?;
+    }
+
+    return Ok(HttpResponse::Found()
+        .header(
+            LOCATION,
+            format!("/admin/semesters/grading/{}", semester_id),
+        )
+        .finish());
+}
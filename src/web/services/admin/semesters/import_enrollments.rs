@@ -0,0 +1,454 @@
+//! Bulk enrollment import from the registrar's CSV.
+//!
+//! Admins upload a CSV with the RCS ID, name, and credits of each student. Rows are matched to
+//! users through their RPI accounts. Admins review a preview of the changes before they are
+//! saved.
+
+use crate::api::rcos::semesters::get_by_id::Semester;
+use crate::api::rcos::users::enrollments::import::{
+    enrollment_import_context, EnrollmentImportContext, ImportEnrollments, Placeholder,
+};
+use crate::error::TelescopeError;
+use crate::templates::page::Page;
+use crate::templates::Template;
+use actix_web::http::header::LOCATION;
+use actix_web::web::{self as aweb, Form, FormConfig, Path, ServiceConfig};
+use actix_web::{HttpRequest, HttpResponse};
+use csv::{ReaderBuilder, StringRecord, Trim};
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+/// The handlebars template for the import form and preview.
+const TEMPLATE_PATH: &'static str = "admin/semesters/import";
+
+/// The largest CSV accepted, in bytes. The default form limit is too small for a full
+/// semester of students.
+const IMPORT_SIZE_LIMIT: usize = 1 << 20;
+
+/// The most credits a student can take RCOS for.
+const MAX_CREDITS: i64 = 4;
+
+lazy_static! {
+    static ref RCS_ID_REGEX: Regex = Regex::new(r"^[a-z0-9]+$").expect("Bad Regex");
+}
+
+/// Register enrollment import services.
+pub fn register(config: &mut ServiceConfig) {
+    let form_config = FormConfig::default().limit(IMPORT_SIZE_LIMIT);
+
+    config
+        .route(
+            "/semesters/import/{semester_id}",
+            aweb::get().to(import_page),
+        )
+        .service(
+            aweb::resource("/semesters/import/{semester_id}/preview")
+                .app_data(form_config.clone())
+                .route(aweb::post().to(preview)),
+        )
+        .service(
+            aweb::resource("/semesters/import/{semester_id}/commit")
+                .app_data(form_config)
+                .route(aweb::post().to(commit)),
+        );
+}
+
+/// A valid row of the CSV.
+#[derive(Clone, Debug, Serialize)]
+struct ImportRow {
+    /// The line number of the row in the CSV.
+    line: usize,
+    rcs_id: String,
+    first_name: String,
+    last_name: String,
+    credits: i64,
+}
+
+/// A row of the CSV that could not be imported.
+#[derive(Clone, Debug, Serialize)]
+struct RowIssue {
+    line: usize,
+    message: String,
+}
+
+/// The columns of the CSV.
+struct Columns {
+    rcs_id: usize,
+    /// Either the full name in one column, or the first and last names in separate columns.
+    name: Option<usize>,
+    first_name: Option<usize>,
+    last_name: Option<usize>,
+    credits: usize,
+}
+
+impl Columns {
+    /// Find the columns from a header row. Return `None` if the row is not a header.
+    fn from_header(record: &StringRecord) -> Option<Result<Self, String>> {
+        // Compare headers by their letters only, so "RCS ID", "rcs_id", and "RCSID" match.
+        let headers: Vec<String> = record
+            .iter()
+            .map(|cell| {
+                cell.chars()
+                    .filter(|c| c.is_ascii_alphanumeric())
+                    .collect::<String>()
+                    .to_lowercase()
+            })
+            .collect();
+        let find = |names: &[&str]| headers.iter().position(|h| names.contains(&h.as_str()));
+
+        let rcs_id = find(&["rcsid", "rcs", "username"]);
+        let name = find(&["name", "fullname", "studentname"]);
+        let first_name = find(&["firstname", "first", "givenname"]);
+        let last_name = find(&["lastname", "last", "surname", "familyname"]);
+        let credits = find(&["credits", "credit", "credithours", "hours"]);
+
+        if rcs_id.is_none() && name.is_none() && first_name.is_none() && credits.is_none() {
+            return None;
+        }
+
+        return Some(match (rcs_id, credits) {
+            (Some(rcs_id), Some(credits)) => Ok(Columns {
+                rcs_id,
+                name,
+                first_name,
+                last_name,
+                credits,
+            }),
+            _ => Err("The header must have an RCS ID column and a credits column.".into()),
+        });
+    }
+
+    /// The columns of a CSV without a header: RCS ID, name, and credits, in that order.
+    fn default_order() -> Self {
+        Columns {
+            rcs_id: 0,
+            name: Some(1),
+            first_name: None,
+            last_name: None,
+            credits: 2,
+        }
+    }
+
+    /// Get the first and last name from a row. Full names are either "Last, First" or
+    /// "First Last".
+    fn names(&self, record: &StringRecord) -> (String, String) {
+        let cell = |index: Option<usize>| {
+            index
+                .and_then(|index| record.get(index))
+                .unwrap_or("")
+                .trim()
+                .to_string()
+        };
+
+        if self.first_name.is_some() || self.last_name.is_some() {
+            return (cell(self.first_name), cell(self.last_name));
+        }
+
+        let name: String = cell(self.name);
+        if let Some((last, first)) = name.split_once(',') {
+            return (first.trim().to_string(), last.trim().to_string());
+        }
+        match name.rsplit_once(' ') {
+            Some((first, last)) => (first.trim().to_string(), last.trim().to_string()),
+            None => (name, String::new()),
+        }
+    }
+}
+
+/// Parse the CSV into valid rows and issues with the other rows.
+fn parse_csv(text: &str) -> (Vec<ImportRow>, Vec<RowIssue>) {
+    let mut rows: Vec<ImportRow> = Vec::new();
+    let mut issues: Vec<RowIssue> = Vec::new();
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut columns: Option<Columns> = None;
+
+    let mut reader = ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .trim(Trim::All)
+        .from_reader(text.as_bytes());
+
+    for (index, result) in reader.records().enumerate() {
+        let line: usize = index + 1;
+        let record: StringRecord = match result {
+            Ok(record) => record,
+            Err(e) => {
+                issues.push(RowIssue {
+                    line,
+                    message: format!("Could not read this row: {}", e),
+                });
+                continue;
+            }
+        };
+
+        // Skip blank rows.
+        if record.iter().all(|cell| cell.is_empty()) {
+            continue;
+        }
+
+        // The first row is either a header or the first student.
+        let columns: &Columns = match columns {
+            Some(ref columns) => columns,
+            None => match Columns::from_header(&record) {
+                Some(Ok(header)) => {
+                    columns = Some(header);
+                    continue;
+                }
+                Some(Err(message)) => {
+                    issues.push(RowIssue { line, message });
+                    return (rows, issues);
+                }
+                None => columns.get_or_insert(Columns::default_order()),
+            },
+        };
+
+        let rcs_id: String = record.get(columns.rcs_id).unwrap_or("").to_lowercase();
+        if !RCS_ID_REGEX.is_match(rcs_id.as_str()) {
+            issues.push(RowIssue {
+                line,
+                message: format!("\"{}\" is not a valid RCS ID.", rcs_id),
+            });
+            continue;
+        }
+
+        let credits: Option<i64> = record
+            .get(columns.credits)
+            .and_then(|cell| cell.parse::<f64>().ok())
+            .filter(|credits| credits.fract() == 0.0)
+            .map(|credits| credits as i64)
+            .filter(|credits| (0..=MAX_CREDITS).contains(credits));
+        let credits: i64 = match credits {
+            Some(credits) => credits,
+            None => {
+                issues.push(RowIssue {
+                    line,
+                    message: format!(
+                        "Credits for {} must be a whole number from 0 to {}.",
+                        rcs_id, MAX_CREDITS
+                    ),
+                });
+                continue;
+            }
+        };
+
+        if !seen.insert(rcs_id.clone()) {
+            issues.push(RowIssue {
+                line,
+                message: format!(
+                    "{} is listed more than once. Only the first row is used.",
+                    rcs_id
+                ),
+            });
+            continue;
+        }
+
+        let (first_name, last_name) = columns.names(&record);
+        rows.push(ImportRow {
+            line,
+            rcs_id,
+            first_name,
+            last_name,
+            credits,
+        });
+    }
+
+    return (rows, issues);
+}
+
+/// A row matched to an existing user.
+#[derive(Clone, Debug, Serialize)]
+struct MatchedRow {
+    row: ImportRow,
+    user_id: Uuid,
+    /// The name of the user in Telescope.
+    name: String,
+    /// The user's current credits, if they are already enrolled.
+    current_credits: Option<i64>,
+}
+
+/// The changes an import would make to a semester.
+#[derive(Clone, Debug, Serialize)]
+struct ImportDiff {
+    /// Users that will be enrolled.
+    new_enrollments: Vec<MatchedRow>,
+    /// Enrollments that will have their credits changed.
+    credit_changes: Vec<MatchedRow>,
+    /// Enrollments that already match the CSV.
+    unchanged: Vec<MatchedRow>,
+    /// Rows with an RCS ID that no user has.
+    unknown: Vec<ImportRow>,
+    /// Rows that could not be read.
+    issues: Vec<RowIssue>,
+}
+
+/// Compare the rows of the CSV with the users and enrollments of a semester.
+fn diff(
+    rows: Vec<ImportRow>,
+    issues: Vec<RowIssue>,
+    context: &enrollment_import_context::ResponseData,
+) -> ImportDiff {
+    let users: HashMap<&str, &enrollment_import_context::EnrollmentImportContextUserAccountsUser> =
+        context
+            .user_accounts
+            .iter()
+            .map(|account| (account.account_id.as_str(), &account.user))
+            .collect();
+
+    let mut diff = ImportDiff {
+        new_enrollments: Vec::new(),
+        credit_changes: Vec::new(),
+        unchanged: Vec::new(),
+        unknown: Vec::new(),
+        issues,
+    };
+
+    for row in rows {
+        let user = match users.get(row.rcs_id.as_str()) {
+            Some(user) => user,
+            None => {
+                diff.unknown.push(row);
+                continue;
+            }
+        };
+
+        let current_credits: Option<i64> = user
+            .enrollments
+            .first()
+            .map(|enrollment| enrollment.credits);
+        let matched = MatchedRow {
+            user_id: user.id,
+            name: format!("{} {}", user.first_name, user.last_name),
+            current_credits,
+            row,
+        };
+
+        match current_credits {
+            None => diff.new_enrollments.push(matched),
+            Some(credits) if credits != matched.row.credits => diff.credit_changes.push(matched),
+            Some(_) => diff.unchanged.push(matched),
+        }
+    }
+
+    return diff;
+}
+
+/// The form submitted with the CSV.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct ImportForm {
+    csv: String,
+
+    /// Checkboxes are only submitted when checked.
+    #[serde(default)]
+    create_placeholders: Option<String>,
+}
+
+/// Parse the submitted CSV and compare it with the semester.
+async fn make_diff(semester_id: &str, form: &ImportForm) -> Result<ImportDiff, TelescopeError> {
+    let (rows, issues) = parse_csv(form.csv.as_str());
+    let rcs_ids: Vec<String> = rows.iter().map(|row| row.rcs_id.clone()).collect();
+    let context = EnrollmentImportContext::get(semester_id.to_string(), rcs_ids).await?;
+
+    if context.semester.is_none() {
+        return Err(TelescopeError::resource_not_found(
+            "Semester Not Found",
+            "Could not find a semester by this ID.",
+        ));
+    }
+
+    return Ok(diff(rows, issues, &context));
+}
+
+/// Page to upload the registrar's CSV for a semester.
+async fn import_page(
+    req: HttpRequest,
+    Path(semester_id): Path<String>,
+) -> Result<Page, TelescopeError> {
+    let semester =
+        Semester::get_by_id(semester_id)
+            .await?
+            .ok_or(TelescopeError::resource_not_found(
+                "Semester Not Found",
+                "Could not find a semester by this ID.",
+            ))?;
+
+    let mut template = Template::new(TEMPLATE_PATH);
+    template.fields = json!({ "semester": semester });
+    return template.in_page(&req, "Import Enrollments").await;
+}
+
+/// Page showing the changes an import would make, without making them.
+async fn preview(
+    req: HttpRequest,
+    Path(semester_id): Path<String>,
+    Form(form): Form<ImportForm>,
+) -> Result<Page, TelescopeError> {
+    let diff: ImportDiff = make_diff(semester_id.as_str(), &form).await?;
+    let semester = Semester::get_by_id(semester_id).await?;
+
+    let has_changes: bool = !diff.new_enrollments.is_empty()
+        || !diff.credit_changes.is_empty()
+        || (form.create_placeholders.is_some() && !diff.unknown.is_empty());
+
+    let mut template = Template::new(TEMPLATE_PATH);
+    template.fields = json!({
+        "semester": semester,
+        "form": form,
+        "counts": {
+            "new_enrollments": diff.new_enrollments.len(),
+            "credit_changes": diff.credit_changes.len(),
+            "unknown": diff.unknown.len(),
+            "unchanged": diff.unchanged.len(),
+        },
+        "diff": diff,
+        "has_changes": has_changes,
+    });
+    return template.in_page(&req, "Import Enrollments").await;
+}
+
+/// Service to save an import. The CSV is compared with the semester again, in case the
+/// enrollments changed since the preview.
+async fn commit(
+    Path(semester_id): Path<String>,
+    Form(form): Form<ImportForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    let diff: ImportDiff = make_diff(semester_id.as_str(), &form).await?;
+
+    let enrollments: Vec<(Uuid, i64)> = diff
+        .new_enrollments
+        .iter()
+        .chain(diff.credit_changes.iter())
+        .map(|matched| (matched.user_id, matched.row.credits))
+        .collect();
+
+    // Unknown RCS IDs are only added as placeholder users if the admin asked for it.
+    let placeholders: Vec<Placeholder> = if form.create_placeholders.is_some() {
+        diff.unknown
+            .into_iter()
+            .map(|row| Placeholder {
+                // Placeholder users need a name, so fall back on the RCS ID.
+                first_name: if row.first_name.is_empty() {
+                    row.rcs_id.clone()
+                } else {
+                    row.first_name
+                },
+                last_name: row.last_name,
+                rcs_id: row.rcs_id,
+                credits: row.credits,
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    if !enrollments.is_empty() || !placeholders.is_empty() {
+        ImportEnrollments::execute(semester_id.clone(), enrollments, placeholders).await?;
+    }
+
+    return Ok(HttpResponse::Found()
+        .header(
+            LOCATION,
+            format!("/admin/semesters/enrollments/{}", semester_id),
+        )
+        .finish());
+}
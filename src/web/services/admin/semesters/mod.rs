@@ -13,11 +13,15 @@ use crate::templates::Template;
 
 mod create;
 mod edit;
+mod grading;
+mod import_enrollments;
 mod view_enrollments;
 
 /// Register semester services.
 pub fn register(config: &mut ServiceConfig) {
     view_enrollments::register_services(config);
+    grading::register(config);
+    import_enrollments::register(config);
 
     config
         .service(create::new)
@@ -5,7 +5,7 @@ use actix_web::http::header::{
 };
 use actix_web::web::{self as aweb, Path, Query, ServiceConfig};
 use actix_web::{HttpRequest, HttpResponse};
-use chrono::Utc;
+use chrono::{SecondsFormat, Utc};
 use csv::WriterBuilder;
 use serde::Serialize;
 use serde_json::Value;
@@ -13,7 +13,9 @@ use uuid::Uuid;
 
 use crate::api::rcos::semesters::get_by_id::semester::SemesterSemestersByPk;
 use crate::api::rcos::semesters::get_by_id::Semester;
-use crate::api::rcos::users::enrollments::enrollments_lookup::EnrollmentsLookup;
+use crate::api::rcos::users::enrollments::enrollments_lookup::{
+    enrollments_lookup::EnrollmentsLookupEnrollments, EnrollmentsLookup,
+};
 use crate::api::rcos::users::enrollments::user_enrollment_lookup::UserEnrollmentLookup;
 use crate::error::TelescopeError;
 use crate::templates::page::Page;
@@ -44,6 +46,41 @@ pub struct Enrollments {
     pub final_grade: String,
     pub created_at: String,
     pub user_id: String,
+    /// Status of the user's request to be paid instead of receiving credit. Empty if they did
+    /// not make one.
+    pub pay_request: String,
+}
+
+impl From<EnrollmentsLookupEnrollments> for Enrollments {
+    fn from(enrollment: EnrollmentsLookupEnrollments) -> Self {
+        // Format optional values as empty strings when they are missing.
+        fn optional<T: ToString>(value: Option<T>) -> String {
+            value.map(|v| v.to_string()).unwrap_or_default()
+        }
+
+        let pay_request: &str = match enrollment.pay_request.map(|request| request.is_approved) {
+            None => "",
+            Some(None) => "pending",
+            Some(Some(true)) => "approved",
+            Some(Some(false)) => "denied",
+        };
+
+        Enrollments {
+            semester_id: enrollment.semester_id,
+            project_id: optional(enrollment.project_id),
+            is_project_lead: enrollment.is_project_lead.to_string(),
+            is_coordinator: enrollment.is_coordinator.to_string(),
+            credits: enrollment.credits.to_string(),
+            is_for_pay: enrollment.is_for_pay.to_string(),
+            mid_year_grade: optional(enrollment.mid_year_grade),
+            final_grade: optional(enrollment.final_grade),
+            created_at: enrollment
+                .created_at
+                .to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            user_id: enrollment.user_id.to_string(),
+            pay_request: pay_request.to_string(),
+        }
+    }
 }
 
 pub fn register_services(conf: &mut ServiceConfig) {
@@ -87,7 +124,7 @@ pub async fn export_to_csv(
         let mut wtr = WriterBuilder::new().from_writer(&mut buffer);
         let api_data = query_response.enrollments;
         for enrollment in api_data {
-            wtr.serialize(enrollment).map_err(|e| {
+            wtr.serialize(Enrollments::from(enrollment)).map_err(|e| {
                 TelescopeError::ise(format!(
                     "There was an issue writing the data to CSV: {:?}",
                     e
@@ -0,0 +1,147 @@
+//! Announcement archive page.
+//!
+//! Active announcements are shown on every page by the page template. Coordinators manage
+//! announcements from the coordinator panel. Announcements are also published as an Atom feed
+//! for feed readers.
+
+use crate::api::rcos::announcements::{
+    announcement_archive::AnnouncementArchiveAnnouncements, AnnouncementArchive,
+};
+use crate::api::rcos::users::navbar_auth::Authentication;
+use crate::env::global_config;
+use crate::error::TelescopeError;
+use crate::templates::helpers::render_markdown;
+use crate::templates::page::Page;
+use crate::templates::Template;
+use crate::web::services::auth::identity::Identity;
+use actix_web::web::ServiceConfig;
+use actix_web::{HttpRequest, HttpResponse};
+use chrono::{DateTime, NaiveDate, Utc};
+
+/// The handlebars template for the announcement archive.
+const TEMPLATE_PATH: &'static str = "announcements/archive";
+
+/// The number of announcements included in the Atom feed.
+const FEED_LENGTH: usize = 50;
+
+/// Register announcement services.
+pub fn register(config: &mut ServiceConfig) {
+    config.service(archive).service(atom_feed);
+}
+
+/// Page listing every announcement.
+#[get("/announcements")]
+async fn archive(req: HttpRequest, identity: Identity) -> Result<Page, TelescopeError> {
+    // Coordinators and admins can manage announcements from this page.
+    let can_manage: bool = match identity.get_user_id().await? {
+        Some(user_id) => {
+            let auth = Authentication::get(user_id).await?;
+            auth.is_coordinating() || auth.is_admin()
+        }
+        None => false,
+    };
+
+    // Announcements without a close date close at the end of their semester.
+    let now = Utc::now();
+    let today: NaiveDate = now.naive_utc().date();
+    let announcements: Vec<_> = AnnouncementArchive::get()
+        .await?
+        .into_iter()
+        .map(|announcement| {
+            let is_closed: bool = match announcement.close_date_time {
+                Some(close) => close <= now,
+                None => announcement.semester.end_date < today,
+            };
+
+            let mut value = json!(announcement);
+            value["is_closed"] = json!(is_closed);
+            value
+        })
+        .collect();
+
+    let mut template = Template::new(TEMPLATE_PATH);
+    template.fields = json!({
+        "announcements": announcements,
+        "can_manage": can_manage,
+    });
+    return template.in_page(&req, "Announcements").await;
+}
+
+/// Escape text for use in an XML document.
+fn escape_xml(text: &str) -> String {
+    v_htmlescape::escape(text).to_string()
+}
+
+/// Make an Atom feed from a list of announcements, most recent first.
+fn make_feed(announcements: &[AnnouncementArchiveAnnouncements], now: DateTime<Utc>) -> String {
+    let telescope_url: String = global_config().telescope_url.clone();
+    // The feed is updated whenever an announcement is made.
+    let updated: DateTime<Utc> = announcements
+        .first()
+        .map(|announcement| announcement.created_at)
+        .unwrap_or(now);
+
+    let mut feed: String = String::new();
+    feed.push_str("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    feed.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+    feed.push_str("  <title>RCOS Announcements</title>\n");
+    feed.push_str(&format!("  <id>{}/announcements</id>\n", telescope_url));
+    feed.push_str(&format!(
+        "  <link rel=\"alternate\" href=\"{}/announcements\"/>\n",
+        telescope_url
+    ));
+    feed.push_str(&format!(
+        "  <link rel=\"self\" href=\"{}/announcements.atom\"/>\n",
+        telescope_url
+    ));
+    feed.push_str(&format!("  <updated>{}</updated>\n", updated.to_rfc3339()));
+    feed.push_str("  <author><name>RCOS</name></author>\n");
+
+    for announcement in announcements {
+        let url: String = format!(
+            "{}/announcements#announcement-{}",
+            telescope_url, announcement.announcement_id
+        );
+
+        feed.push_str("  <entry>\n");
+        feed.push_str(&format!(
+            "    <title>{}</title>\n",
+            escape_xml(&announcement.title)
+        ));
+        feed.push_str(&format!("    <id>{}</id>\n", url));
+        feed.push_str(&format!("    <link rel=\"alternate\" href=\"{}\"/>\n", url));
+        feed.push_str(&format!(
+            "    <published>{}</published>\n",
+            announcement.created_at.to_rfc3339()
+        ));
+        feed.push_str(&format!(
+            "    <updated>{}</updated>\n",
+            announcement.created_at.to_rfc3339()
+        ));
+        feed.push_str(&format!(
+            "    <category term=\"{}\" label=\"{}\"/>\n",
+            escape_xml(&announcement.semester.semester_id),
+            escape_xml(&announcement.semester.title)
+        ));
+        // The rendered HTML is escaped again to embed it in the feed.
+        feed.push_str(&format!(
+            "    <content type=\"html\">{}</content>\n",
+            escape_xml(&render_markdown(&announcement.body_markdown))
+        ));
+        feed.push_str("  </entry>\n");
+    }
+
+    feed.push_str("</feed>\n");
+    return feed;
+}
+
+/// Atom feed of the most recent announcements.
+#[get("/announcements.atom")]
+async fn atom_feed() -> Result<HttpResponse, TelescopeError> {
+    let mut announcements = AnnouncementArchive::get().await?;
+    announcements.truncate(FEED_LENGTH);
+
+    return Ok(HttpResponse::Ok()
+        .content_type("application/atom+xml; charset=utf-8")
+        .body(make_feed(&announcements, Utc::now())));
+}
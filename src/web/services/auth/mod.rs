@@ -1,4 +1,4 @@
-use crate::api::discord::global_discord_client;
+use crate::api::discord::{global_discord_client, on_discord_runtime};
 use crate::api::rcos::users::accounts::for_user::UserAccounts;
 use crate::api::rcos::users::accounts::unlink::UnlinkUserAccount;
 use crate::api::rcos::users::UserAccountType;
@@ -241,10 +241,11 @@ pub trait IdentityProvider: 'static {
                         let rcos_discord = global_config().discord_config.rcos_guild_id();
 
                         // Kick user from RCOS Discord.
-                        global_discord_client()
-                            .kick_member(rcos_discord, discord_id)
-                            .await
-                            .map_err(TelescopeError::serenity_error)?
+                        on_discord_runtime(
+                            global_discord_client().kick_member(rcos_discord, discord_id),
+                        )
+                        .await
+                        .map_err(TelescopeError::serenity_error)?
                     }
                 }
 
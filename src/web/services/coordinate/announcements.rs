@@ -0,0 +1,314 @@
+//! Announcement management services for coordinators.
+
+use crate::api::discord::announcements as discord;
+use crate::api::rcos::announcements::{
+    Announcement, AnnouncementSemesters, CloseAnnouncement, CreateAnnouncement,
+    DeleteAnnouncementDiscordMessage, EditAnnouncement, SaveAnnouncementDiscordMessage,
+};
+use crate::error::TelescopeError;
+use crate::templates::page::Page;
+use crate::templates::Template;
+use actix_web::http::header::LOCATION;
+use actix_web::web::{Form, Path, ServiceConfig};
+use actix_web::{HttpRequest, HttpResponse};
+use chrono::{DateTime, Local, NaiveDate, NaiveTime, TimeZone, Utc};
+
+/// The handlebars template for the announcement form.
+const TEMPLATE_PATH: &'static str = "coordinate/announcements/form";
+
+/// Register announcement management services. These are registered in the coordinator scope.
+pub fn register(config: &mut ServiceConfig) {
+    config
+        .service(create_page)
+        .service(create)
+        .service(edit_page)
+        .service(edit)
+        .service(close_announcement);
+}
+
+/// Make the announcement form.
+async fn make_form() -> Result<Template, TelescopeError> {
+    let mut form = Template::new(TEMPLATE_PATH);
+    form.fields = json!({
+        "semesters": AnnouncementSemesters::get().await?,
+    });
+    return Ok(form);
+}
+
+/// Page to make an announcement.
+#[get("/announcements/create")]
+async fn create_page(req: HttpRequest) -> Result<Page, TelescopeError> {
+    make_form()
+        .await?
+        .in_page(&req, "Make an Announcement")
+        .await
+}
+
+/// Page to edit an announcement.
+#[get("/announcements/{announcement_id}/edit")]
+async fn edit_page(
+    req: HttpRequest,
+    Path(announcement_id): Path<i64>,
+) -> Result<Page, TelescopeError> {
+    let announcement =
+        Announcement::get(announcement_id)
+            .await?
+            .ok_or(TelescopeError::resource_not_found(
+                "Announcement Not Found",
+                "Could not find an announcement for this ID.",
+            ))?;
+
+    let mut form: Template = make_form().await?;
+    form["announcement_id"] = json!(announcement_id);
+    form["selections"] = json!({
+        "semester": announcement.semester_id,
+        "title": announcement.title,
+        "body_markdown": announcement.body_markdown,
+    });
+
+    // Show the close time in local time.
+    if let Some(close) = announcement.close_date_time {
+        let close = close.with_timezone(&Local);
+        form["selections"]["close_date"] = json!(close.format("%Y-%m-%d").to_string());
+        form["selections"]["close_time"] = json!(close.format("%H:%M").to_string());
+    }
+
+    return form.in_page(&req, "Edit Announcement").await;
+}
+
+/// Form submitted by coordinators to make or edit an announcement.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct AnnouncementForm {
+    semester: String,
+
+    title: String,
+
+    #[serde(default)]
+    body_markdown: String,
+
+    /// Optional date to stop showing the announcement. Without one, the announcement is
+    /// shown until the end of the semester.
+    #[serde(default)]
+    close_date: String,
+
+    /// Optional time on the close date. Defaults to the end of the day.
+    #[serde(default)]
+    close_time: String,
+}
+
+/// Validate a submitted announcement form. Return the close time of the announcement if the
+/// form is valid, otherwise set issues on the returned form.
+fn validate(form: &AnnouncementForm, return_form: &mut Template) -> Option<DateTime<Utc>> {
+    if form.title.trim().is_empty() {
+        return_form["issues"]["title"] = json!("Announcements need a title.");
+    }
+
+    let known_semester: bool = return_form["semesters"]
+        .as_array()
+        .map(|semesters| {
+            semesters
+                .iter()
+                .any(|semester| semester["semester_id"] == form.semester.as_str())
+        })
+        .unwrap_or(false);
+    if !known_semester {
+        return_form["issues"]["semester"] = json!("Please select a semester.");
+    }
+
+    if form.close_date.trim().is_empty() {
+        return None;
+    }
+
+    let date: Option<NaiveDate> = form.close_date.trim().parse::<NaiveDate>().ok();
+    let time: Option<NaiveTime> = match form.close_time.trim() {
+        "" => Some(NaiveTime::from_hms(23, 59, 59)),
+        // Times from the browser do not include seconds.
+        time => format!("{}:00", time).parse::<NaiveTime>().ok(),
+    };
+
+    // Close times are entered in local time.
+    let close: Option<DateTime<Utc>> = date
+        .zip(time)
+        .and_then(|(date, time)| Local.from_local_datetime(&date.and_time(time)).earliest())
+        .map(|close| close.with_timezone(&Utc));
+
+    if close.is_none() {
+        return_form["issues"]["close_date"] = json!("Please enter a valid date and time.");
+    }
+
+    return close;
+}
+
+/// Parse a Discord ID stored in the database.
+fn parse_discord_id(id: &str) -> Result<u64, TelescopeError> {
+    id.parse::<u64>()
+        .map_err(|_| TelescopeError::ise(format!("Malformed Discord ID: {}", id)))
+}
+
+/// Bring the Discord message broadcasting an announcement up to date. Open announcements are
+/// posted or edited, and closed announcements are deleted from Discord.
+async fn sync_discord_message(announcement_id: i64) -> Result<(), TelescopeError> {
+    let announcement =
+        Announcement::get(announcement_id)
+            .await?
+            .ok_or(TelescopeError::resource_not_found(
+                "Announcement Not Found",
+                "Could not find an announcement for this ID.",
+            ))?;
+
+    // Announcements without a close date close at the end of their semester.
+    let now: DateTime<Utc> = Utc::now();
+    let is_open: bool = match announcement.close_date_time {
+        Some(close) => close > now,
+        None => announcement.semester.end_date >= now.naive_utc().date(),
+    };
+
+    match (announcement.discord_message, is_open) {
+        (Some(message), true) => {
+            discord::edit_announcement(
+                parse_discord_id(&message.channel_id)?,
+                parse_discord_id(&message.message_id)?,
+                announcement_id,
+                &announcement.title,
+                &announcement.body_markdown,
+            )
+            .await?;
+        }
+
+        (Some(message), false) => {
+            discord::delete_announcement(
+                parse_discord_id(&message.channel_id)?,
+                parse_discord_id(&message.message_id)?,
+            )
+            .await?;
+            DeleteAnnouncementDiscordMessage::execute(announcement_id).await?;
+        }
+
+        (None, true) => {
+            let posted: Option<(u64, u64)> = discord::post_announcement(
+                announcement_id,
+                &announcement.title,
+                &announcement.body_markdown,
+            )
+            .await?;
+
+            if let Some((channel_id, message_id)) = posted {
+                SaveAnnouncementDiscordMessage::execute(announcement_id, channel_id, message_id)
+                    .await?;
+            }
+        }
+
+        (None, false) => {}
+    }
+
+    return Ok(());
+}
+
+/// Update the Discord broadcast of an announcement after it changes. The announcement is
+/// already saved at this point, so failures are logged instead of shown to the coordinator.
+async fn broadcast(announcement_id: i64) {
+    if let Err(err) = sync_discord_message(announcement_id).await {
+        error!(
+            "Could not update Discord message for announcement {}: {}",
+            announcement_id, err
+        );
+    }
+}
+
+/// Endpoint to make an announcement.
+#[post("/announcements/create")]
+async fn create(
+    req: HttpRequest,
+    Form(form): Form<AnnouncementForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    let mut return_form: Template = make_form().await?;
+    return_form["selections"] = json!(&form);
+
+    let close: Option<DateTime<Utc>> = validate(&form, &mut return_form);
+    if !return_form["issues"].is_null() {
+        let page = return_form.in_page(&req, "Make an Announcement").await?;
+        return Err(TelescopeError::InvalidForm(page));
+    }
+
+    let announcement_id: i64 = CreateAnnouncement::execute(
+        form.semester,
+        form.title.trim().to_string(),
+        form.body_markdown.trim().to_string(),
+        close,
+    )
+    .await?
+    .ok_or(TelescopeError::ise(
+        "Announcement creation did not return an ID.",
+    ))?;
+
+    broadcast(announcement_id).await;
+
+    return Ok(HttpResponse::Found()
+        .header(
+            LOCATION,
+            format!("/announcements#announcement-{}", announcement_id),
+        )
+        .finish());
+}
+
+/// Endpoint to edit an announcement.
+#[post("/announcements/{announcement_id}/edit")]
+async fn edit(
+    req: HttpRequest,
+    Path(announcement_id): Path<i64>,
+    Form(form): Form<AnnouncementForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    let mut return_form: Template = make_form().await?;
+    return_form["announcement_id"] = json!(announcement_id);
+    return_form["selections"] = json!(&form);
+
+    let close: Option<DateTime<Utc>> = validate(&form, &mut return_form);
+    if !return_form["issues"].is_null() {
+        let page = return_form.in_page(&req, "Edit Announcement").await?;
+        return Err(TelescopeError::InvalidForm(page));
+    }
+
+    EditAnnouncement::execute(
+        announcement_id,
+        form.semester,
+        form.title.trim().to_string(),
+        form.body_markdown.trim().to_string(),
+        close,
+    )
+    .await?
+    .ok_or(TelescopeError::resource_not_found(
+        "Announcement Not Found",
+        "Could not find an announcement for this ID.",
+    ))?;
+
+    broadcast(announcement_id).await;
+
+    return Ok(HttpResponse::Found()
+        .header(
+            LOCATION,
+            format!("/announcements#announcement-{}", announcement_id),
+        )
+        .finish());
+}
+
+/// Endpoint to stop showing an announcement.
+#[post("/announcements/{announcement_id}/close")]
+async fn close_announcement(
+    Path(announcement_id): Path<i64>,
+) -> Result<HttpResponse, TelescopeError> {
+    CloseAnnouncement::execute(announcement_id).await?.ok_or(
+        TelescopeError::resource_not_found(
+            "Announcement Not Found",
+            "Could not find an announcement for this ID.",
+        ),
+    )?;
+
+    broadcast(announcement_id).await;
+
+    return Ok(HttpResponse::Found()
+        .header(
+            LOCATION,
+            format!("/announcements#announcement-{}", announcement_id),
+        )
+        .finish());
+}
@@ -0,0 +1,139 @@
+//! Meeting attendance roster and manual attendance editing.
+//!
+//! These services are available to coordinators and to the host of the meeting, so they are
+//! registered in their own scope rather than behind the coordinator authorization middleware.
+
+use crate::api::rcos::meetings::attendance::attendance_roster::AttendanceRosterMeeting;
+use crate::api::rcos::meetings::attendance::{AddAttendance, AttendanceRoster, RemoveAttendance};
+use crate::api::rcos::meetings::authorization_for::{AuthorizationFor, UserMeetingAuthorization};
+use crate::error::TelescopeError;
+use crate::templates::page::Page;
+use crate::templates::Template;
+use crate::web::services::auth::identity::AuthenticationCookie;
+use crate::web::services::meetings::make_meeting_auth_middleware;
+use actix_web::http::header::LOCATION;
+use actix_web::web as aweb;
+use actix_web::web::{Form, Path, ServiceConfig};
+use actix_web::{HttpRequest, HttpResponse};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// The path from the templates directory to the roster template.
+const TEMPLATE_PATH: &'static str = "coordinate/meetings/attendance";
+
+/// Register attendance roster services. This must be registered before the coordinator scope,
+/// since that scope would otherwise match these paths first.
+pub fn register(config: &mut ServiceConfig) {
+    // Per-meeting access is checked in each service, since the host of a meeting may not be
+    // a coordinator.
+    let authorization =
+        make_meeting_auth_middleware(&UserMeetingAuthorization::can_manage_attendance);
+
+    config.service(
+        aweb::scope("/coordinate/meetings/{meeting_id}/attendance")
+            .wrap(authorization)
+            .service(roster)
+            .service(add_attendance)
+            .service(remove_attendance),
+    );
+}
+
+/// Form submitted to add or remove a user's attendance.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct AttendanceForm {
+    /// The user whose attendance is being changed.
+    user_id: Uuid,
+}
+
+/// Check that the authenticated user can edit a meeting (and therefore its attendance).
+async fn require_edit_access(
+    auth: &AuthenticationCookie,
+    meeting_id: i64,
+) -> Result<(), TelescopeError> {
+    let user_id = auth.get_user_id_or_error().await?;
+    let authorization: UserMeetingAuthorization = AuthorizationFor::get(Some(user_id)).await?;
+    if !authorization.can_edit_by_id(meeting_id).await? {
+        return Err(TelescopeError::Forbidden);
+    }
+    return Ok(());
+}
+
+/// Redirect back to the roster page for a meeting.
+fn redirect_to_roster(meeting_id: i64) -> HttpResponse {
+    HttpResponse::Found()
+        .header(
+            LOCATION,
+            format!("/coordinate/meetings/{}/attendance", meeting_id),
+        )
+        .finish()
+}
+
+/// Page listing every user enrolled in a meeting's semester and their attendance.
+#[get("")]
+async fn roster(
+    req: HttpRequest,
+    auth: AuthenticationCookie,
+    Path(meeting_id): Path<i64>,
+) -> Result<Page, TelescopeError> {
+    require_edit_access(&auth, meeting_id).await?;
+
+    // Get the roster.
+    let meeting: AttendanceRosterMeeting = AttendanceRoster::get(meeting_id)
+        .await?
+        .ok_or(TelescopeError::resource_not_found(
+            "Meeting Not Found",
+            "Could not find a meeting for this ID.",
+        ))?;
+
+    // Index attendances by user ID to match them with enrollments.
+    let attendances: HashMap<Uuid, _> = meeting
+        .meeting_attendances
+        .iter()
+        .map(|attendance| (attendance.user_id, attendance))
+        .collect();
+
+    // Build a row for each enrolled user.
+    let rows: Vec<_> = meeting
+        .semester
+        .enrollments
+        .iter()
+        .map(|enrollment| {
+            json!({
+                "user": &enrollment.user,
+                "attendance": attendances.get(&enrollment.user.id),
+            })
+        })
+        .collect();
+
+    let mut template = Template::new(TEMPLATE_PATH);
+    template.fields = json!({
+        "meeting": &meeting,
+        "rows": rows,
+        "attended_count": meeting.meeting_attendances.len(),
+    });
+    return template.in_page(&req, "Attendance Roster").await;
+}
+
+/// Endpoint to manually mark a user present at a meeting.
+#[post("/add")]
+async fn add_attendance(
+    auth: AuthenticationCookie,
+    Path(meeting_id): Path<i64>,
+    Form(form): Form<AttendanceForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    require_edit_access(&auth, meeting_id).await?;
+    AddAttendance::execute(meeting_id, form.user_id).await?;
+    return Ok(redirect_to_roster(meeting_id));
+}
+
+/// Endpoint to remove a user's attendance at a meeting.
+#[post("/remove")]
+async fn remove_attendance(
+    auth: AuthenticationCookie,
+    Path(meeting_id): Path<i64>,
+    Form(form): Form<AttendanceForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    require_edit_access(&auth, meeting_id).await?;
+    RemoveAttendance::execute(meeting_id, form.user_id).await?;
+    return Ok(redirect_to_roster(meeting_id));
+}
@@ -11,11 +11,16 @@ use futures::future::LocalBoxFuture;
 use crate::api::rcos::users::navbar_auth::Authentication;
 use uuid::Uuid;
 
+mod announcements;
 mod enrollments;
-//mod project_pitches;
-//mod meetings;
+mod meetings;
+mod presentations;
+mod project_pitches;
+mod small_groups;
+mod workshops;
 
-fn coordinator_authorization(user_id: Uuid) -> LocalBoxFuture<'static, AuthorizationResult>{
+/// Check that a user is a current coordinator or an admin.
+pub fn coordinator_authorization(user_id: Uuid) -> LocalBoxFuture<'static, AuthorizationResult>{
     Box::pin(async move{
         // We use navbar_auth here to see if the user is currently coordinating or is an is an
         // admin. Could probably do to rename navbar_auth?
@@ -42,10 +47,19 @@ pub fn register(config: &mut ServiceConfig) {
         .to(index),
         );
 
+    // Meeting attendance rosters are also available to meeting hosts, so they use their own
+    // authorization and must be registered before the coordinator scope.
+    meetings::register(config);
+
     config.service(
         aweb::scope("/coordinate/")
         .wrap(coordinator_authorization_middleware)
+        .configure(announcements::register)
         .configure(enrollments::register)
+        .configure(presentations::register)
+        .configure(project_pitches::register)
+        .configure(small_groups::register)
+        .configure(workshops::register)
 //        .configure(semesters::register),
         );
 }
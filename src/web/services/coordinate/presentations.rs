@@ -0,0 +1,338 @@
+//! End of semester project presentation grading services for coordinators.
+//!
+//! Coordinators open a grading session for a semester with a rubric. Each grader scores every
+//! project against each criterion of the rubric (see the engagement panel's presentation
+//! services), and the results page aggregates the scores.
+
+use crate::api::rcos::presentations::{
+    presentation_session, AddRubricCriterion, OpenPresentationSession, PresentationSession,
+    RemoveRubricCriterion, SetPresentationSessionOpen,
+};
+use crate::error::TelescopeError;
+use crate::templates::page::Page;
+use crate::templates::Template;
+use actix_web::http::header::LOCATION;
+use actix_web::web::{Form, Path, ServiceConfig};
+use actix_web::{HttpRequest, HttpResponse};
+use serde_json::Value;
+
+/// The handlebars template for the grading session and results.
+const SESSION_TEMPLATE: &'static str = "coordinate/presentations/session";
+
+/// The rubric of new grading sessions, as the title, description, and maximum score of each
+/// criterion. Coordinators can change it before anyone grades.
+const DEFAULT_RUBRIC: [(&'static str, &'static str, i64); 3] = [
+    (
+        "Technical Depth",
+        "How challenging the project's work is and how well it is engineered.",
+        10,
+    ),
+    (
+        "Progress",
+        "How much the project accomplished this semester.",
+        10,
+    ),
+    (
+        "Delivery",
+        "How clear and well organized the presentation is.",
+        10,
+    ),
+];
+
+/// Register presentation grading services. These are registered in the coordinator scope.
+pub fn register(config: &mut ServiceConfig) {
+    config
+        .service(session_page)
+        .service(open_session)
+        .service(close_session)
+        .service(add_criterion)
+        .service(remove_criterion);
+}
+
+/// Get the presentation grading session of a semester, or an error if the semester does not
+/// exist.
+async fn get_session(
+    semester_id: &str,
+) -> Result<presentation_session::ResponseData, TelescopeError> {
+    let data = PresentationSession::get(semester_id.to_string()).await?;
+    if data.semester.is_none() {
+        return Err(TelescopeError::resource_not_found(
+            "Semester Not Found",
+            "Could not find a semester for this ID.",
+        ));
+    }
+    return Ok(data);
+}
+
+/// Error for semesters that do not have a grading session open.
+fn session_not_open() -> TelescopeError {
+    TelescopeError::BadRequest {
+        header: "Grading Session Not Open".into(),
+        message: "Presentation grading is not open for this semester.".into(),
+        show_status_code: false,
+    }
+}
+
+/// Redirect to the grading session of a semester.
+fn redirect_to_session(semester_id: &str) -> HttpResponse {
+    HttpResponse::Found()
+        .header(
+            LOCATION,
+            format!("/coordinate/semesters/presentations/{}", semester_id),
+        )
+        .finish()
+}
+
+/// Get the mean and sample standard deviation of a list of values. The standard deviation
+/// needs at least two values.
+fn mean_and_std_dev(values: &[f64]) -> (Option<f64>, Option<f64>) {
+    if values.is_empty() {
+        return (None, None);
+    }
+
+    let n: f64 = values.len() as f64;
+    let mean: f64 = values.iter().sum::<f64>() / n;
+    if values.len() < 2 {
+        return (Some(mean), None);
+    }
+
+    let variance: f64 = values
+        .iter()
+        .map(|value| (value - mean).powi(2))
+        .sum::<f64>()
+        / (n - 1.0);
+    return (Some(mean), Some(variance.sqrt()));
+}
+
+/// Format a statistic for display.
+fn format_stat(value: Option<f64>) -> Value {
+    value
+        .map(|value| json!(format!("{:.2}", value)))
+        .unwrap_or(Value::Null)
+}
+
+/// Page showing the presentation grading session of a semester, its rubric, and the
+/// aggregated grades of each project.
+#[get("/semesters/presentations/{semester_id}")]
+async fn session_page(
+    req: HttpRequest,
+    Path(semester_id): Path<String>,
+) -> Result<Page, TelescopeError> {
+    let data = get_session(semester_id.as_str()).await?;
+    let session = data
+        .semester
+        .as_ref()
+        .and_then(|semester| semester.presentation_grading_session.as_ref());
+    let criteria = session
+        .map(|session| session.criteria.as_slice())
+        .unwrap_or(&[]);
+
+    let results: Vec<Value> = data
+        .projects
+        .iter()
+        .map(|project| {
+            let totals: Vec<f64> = project
+                .project_presentation_grades
+                .iter()
+                .map(|grade| grade.grade)
+                .collect();
+            let (mean, std_dev) = mean_and_std_dev(&totals);
+
+            // The mean score for each criterion, in rubric order.
+            let criterion_means: Vec<Value> = criteria
+                .iter()
+                .map(|criterion| {
+                    let scores: Vec<f64> = project
+                        .project_presentation_grades
+                        .iter()
+                        .flat_map(|grade| grade.criterion_scores.iter())
+                        .filter(|score| score.criterion_id == criterion.criterion_id)
+                        .map(|score| score.score)
+                        .collect();
+                    format_stat(mean_and_std_dev(&scores).0)
+                })
+                .collect();
+
+            json!({
+                "project_id": project.project_id,
+                "title": project.title,
+                "presentation": project.project_presentations.first(),
+                "grades": project.project_presentation_grades,
+                "grader_count": totals.len(),
+                "mean": format_stat(mean),
+                "std_dev": format_stat(std_dev),
+                "criterion_means": criterion_means,
+            })
+        })
+        .collect();
+
+    // The rubric can only change before anyone grades, so that totals stay comparable.
+    let has_grades: bool = data
+        .projects
+        .iter()
+        .any(|project| !project.project_presentation_grades.is_empty());
+    let max_total: i64 = criteria.iter().map(|criterion| criterion.max_score).sum();
+
+    let mut template = Template::new(SESSION_TEMPLATE);
+    template.fields = json!({
+        "semester": &data.semester,
+        "session": session,
+        "results": results,
+        "has_grades": has_grades,
+        "max_total": max_total,
+    });
+    return template.in_page(&req, "Presentation Grading").await;
+}
+
+/// Endpoint to open the presentation grading session of a semester. New sessions start with
+/// the default rubric.
+#[post("/semesters/presentations/{semester_id}/open")]
+async fn open_session(Path(semester_id): Path<String>) -> Result<HttpResponse, TelescopeError> {
+    let data = get_session(semester_id.as_str()).await?;
+    let is_new: bool = data
+        .semester
+        .as_ref()
+        .map(|semester| semester.presentation_grading_session.is_none())
+        .unwrap_or(false);
+
+    let criteria: Vec<(String, String, i64)> = if is_new {
+        DEFAULT_RUBRIC
+            .iter()
+            .map(|(title, description, max_score)| {
+                (title.to_string(), description.to_string(), *max_score)
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    OpenPresentationSession::execute(semester_id.clone(), criteria).await?;
+
+    return Ok(redirect_to_session(semester_id.as_str()));
+}
+
+/// Endpoint to close the presentation grading session of a semester. Graders can no longer
+/// submit or change scores.
+#[post("/semesters/presentations/{semester_id}/close")]
+async fn close_session(Path(semester_id): Path<String>) -> Result<HttpResponse, TelescopeError> {
+    get_session(semester_id.as_str()).await?;
+    SetPresentationSessionOpen::execute(semester_id.clone(), false).await?;
+    return Ok(redirect_to_session(semester_id.as_str()));
+}
+
+/// Check that the rubric of a semester can still change. This is only until the first grade
+/// is submitted.
+fn check_rubric_editable(data: &presentation_session::ResponseData) -> Result<(), TelescopeError> {
+    let has_session: bool = data
+        .semester
+        .as_ref()
+        .map(|semester| semester.presentation_grading_session.is_some())
+        .unwrap_or(false);
+    if !has_session {
+        return Err(session_not_open());
+    }
+
+    let has_grades: bool = data
+        .projects
+        .iter()
+        .any(|project| !project.project_presentation_grades.is_empty());
+    if has_grades {
+        return Err(TelescopeError::BadRequest {
+            header: "Rubric Locked".into(),
+            message: "The rubric cannot change after presentations have been graded.".into(),
+            show_status_code: false,
+        });
+    }
+
+    return Ok(());
+}
+
+/// Form submitted by coordinators to add a criterion to the rubric.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct CriterionForm {
+    title: String,
+
+    #[serde(default)]
+    description: String,
+
+    max_score: i64,
+}
+
+/// Endpoint to add a criterion to the rubric of a semester's grading session.
+#[post("/semesters/presentations/{semester_id}/criteria/add")]
+async fn add_criterion(
+    Path(semester_id): Path<String>,
+    Form(form): Form<CriterionForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    let data = get_session(semester_id.as_str()).await?;
+    check_rubric_editable(&data)?;
+
+    let title: &str = form.title.trim();
+    let is_taken: bool = data
+        .semester
+        .as_ref()
+        .and_then(|semester| semester.presentation_grading_session.as_ref())
+        .map(|session| {
+            session
+                .criteria
+                .iter()
+                .any(|criterion| criterion.title.eq_ignore_ascii_case(title))
+        })
+        .unwrap_or(false);
+
+    if title.is_empty() || is_taken || form.max_score <= 0 {
+        return Err(TelescopeError::BadRequest {
+            header: "Invalid Criterion".into(),
+            message: "Criteria need a unique title and a maximum score above zero.".into(),
+            show_status_code: false,
+        });
+    }
+
+    AddRubricCriterion::execute(
+        semester_id.clone(),
+        title.to_string(),
+        form.description.trim().to_string(),
+        form.max_score,
+    )
+    .await?;
+
+    return Ok(redirect_to_session(semester_id.as_str()));
+}
+
+/// Form submitted by coordinators to remove a criterion from the rubric.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct RemoveCriterionForm {
+    criterion_id: i64,
+}
+
+/// Endpoint to remove a criterion from the rubric of a semester's grading session.
+#[post("/semesters/presentations/{semester_id}/criteria/remove")]
+async fn remove_criterion(
+    Path(semester_id): Path<String>,
+    Form(form): Form<RemoveCriterionForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    let data = get_session(semester_id.as_str()).await?;
+    check_rubric_editable(&data)?;
+
+    // Only remove criteria from this semester's rubric.
+    let in_rubric: bool = data
+        .semester
+        .as_ref()
+        .and_then(|semester| semester.presentation_grading_session.as_ref())
+        .map(|session| {
+            session
+                .criteria
+                .iter()
+                .any(|criterion| criterion.criterion_id == form.criterion_id)
+        })
+        .unwrap_or(false);
+    if !in_rubric {
+        return Err(TelescopeError::resource_not_found(
+            "Criterion Not Found",
+            "Could not find this criterion in the rubric.",
+        ));
+    }
+
+    RemoveRubricCriterion::execute(form.criterion_id).await?;
+    return Ok(redirect_to_session(semester_id.as_str()));
+}
@@ -0,0 +1,147 @@
+//! Project pitch review services for coordinators.
+//!
+//! Approving a pitch for a new project creates the project and links it to the pitch. Approved
+//! pitches cannot be rejected.
+
+use crate::api::rcos::projects::create::{CreateProject, PitchApproval};
+use crate::api::rcos::projects::pitches::{review_pitch, GetPitch, PitchQueue, ReviewPitch};
+use crate::error::TelescopeError;
+use crate::templates::page::Page;
+use crate::templates::Template;
+use crate::web::services::auth::identity::AuthenticationCookie;
+use actix_web::http::header::LOCATION;
+use actix_web::web::{Form, Path, ServiceConfig};
+use actix_web::{HttpRequest, HttpResponse};
+use uuid::Uuid;
+
+/// The handlebars template for the pitch review queue.
+const TEMPLATE_PATH: &'static str = "coordinate/project_pitches";
+
+/// Register project pitch review services. These are registered in the coordinator scope.
+pub fn register(config: &mut ServiceConfig) {
+    config.service(queue).service(review);
+}
+
+/// Page listing the project pitches for a semester.
+#[get("/semesters/pitches/{semester_id}")]
+async fn queue(req: HttpRequest, Path(semester_id): Path<String>) -> Result<Page, TelescopeError> {
+    let semester =
+        PitchQueue::get(semester_id)
+            .await?
+            .ok_or(TelescopeError::resource_not_found(
+                "Semester Not Found",
+                "Could not find a semester for this ID.",
+            ))?;
+
+    let mut template = Template::new(TEMPLATE_PATH);
+    template.fields = json!({
+        "semester": semester,
+    });
+    return template.in_page(&req, "Project Pitches").await;
+}
+
+/// Form submitted by coordinators to review a pitch.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct ReviewForm {
+    /// Either "approve" or "reject".
+    decision: String,
+
+    #[serde(default)]
+    reviewer_comments: String,
+}
+
+/// Endpoint to approve or reject a project pitch.
+#[post("/semesters/pitches/{semester_id}/{user_id}/review")]
+async fn review(
+    auth: AuthenticationCookie,
+    Path((semester_id, user_id)): Path<(String, Uuid)>,
+    Form(form): Form<ReviewForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    let reviewer_id: Uuid = auth.get_user_id_or_error().await?;
+
+    let is_approved: bool = match form.decision.as_str() {
+        "approve" => true,
+        "reject" => false,
+        _ => {
+            return Err(TelescopeError::BadRequest {
+                header: "Invalid Decision".into(),
+                message: "Pitches can only be approved or rejected.".into(),
+                show_status_code: false,
+            })
+        }
+    };
+
+    let pitch = GetPitch::get(semester_id.clone(), user_id).await?.ok_or(
+        TelescopeError::resource_not_found(
+            "Pitch Not Found",
+            "Could not find a project pitch by this user for this semester.",
+        ),
+    )?;
+
+    // An approved pitch may have created a project, so it cannot be rejected afterwards.
+    if pitch.is_approved && !is_approved {
+        return Err(TelescopeError::BadRequest {
+            header: "Pitch Already Approved".into(),
+            message: "This pitch has already been approved and cannot be rejected.".into(),
+            show_status_code: false,
+        });
+    }
+
+    let comments: Option<String> = (!form.reviewer_comments.trim().is_empty())
+        .then(|| form.reviewer_comments.trim().to_string());
+
+    if is_approved && pitch.existing_project_id.is_none() {
+        // Approving a pitch for a new project creates the project.
+        // Stacks are entered as comma separated lists.
+        let stack: Vec<String> = pitch
+            .proposed_stack
+            .as_deref()
+            .unwrap_or("")
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(String::from)
+            .collect();
+
+        CreateProject::execute(
+            pitch.proposed_title,
+            stack,
+            Vec::new(),
+            None,
+            Some(pitch.proposed_description.unwrap_or_default()),
+            None,
+            Some(PitchApproval {
+                semester_id: semester_id.clone(),
+                user_id,
+                reviewer_id,
+                reviewer_comments: comments,
+            }),
+        )
+        .await?
+        .ok_or(TelescopeError::ise(
+            "Project creation call did not return ID.",
+        ))?;
+    } else {
+        ReviewPitch::execute(review_pitch::Variables {
+            semester_id: semester_id.clone(),
+            user_id,
+            reviewer_id,
+            is_approved,
+            reviewer_comments: comments,
+            existing_project_id: pitch.existing_project_id,
+        })
+        .await?
+        .ok_or(TelescopeError::resource_not_found(
+            "Pitch Not Found",
+            "Could not find a project pitch by this user for this semester.",
+        ))?;
+    }
+
+    // Send the coordinator back to the queue.
+    return Ok(HttpResponse::Found()
+        .header(
+            LOCATION,
+            format!("/coordinate/semesters/pitches/{}", semester_id),
+        )
+        .finish());
+}
@@ -0,0 +1,330 @@
+//! Automatic balancing of projects between small groups.
+//!
+//! Telescope proposes a small group for every project with students enrolled in a semester.
+//! Coordinators review and adjust the proposal before it replaces the semester's small group
+//! projects.
+
+use crate::api::rcos::small_groups::{
+    small_group_balance, AssignSmallGroupProjects, PreviousSmallGroups, SmallGroupBalance,
+};
+use crate::error::TelescopeError;
+use crate::templates::page::Page;
+use crate::templates::Template;
+use actix_web::http::header::LOCATION;
+use actix_web::web::{Form, Path, Query, ServiceConfig};
+use actix_web::{HttpRequest, HttpResponse};
+use std::collections::HashMap;
+
+/// The handlebars template for the balancing proposal.
+const TEMPLATE_PATH: &'static str = "coordinate/small_groups/balance";
+
+/// How much larger than its share of students a small group can get to keep a project where
+/// it was. Projects that would push a small group past this are moved instead.
+const KEEP_TOLERANCE: f64 = 0.15;
+
+/// The default maximum size of a small group, relative to the average small group size.
+const DEFAULT_MAX_SIZE_FACTOR: f64 = 1.25;
+
+/// Register small group balancing services. These are registered in the coordinator scope.
+pub fn register(config: &mut ServiceConfig) {
+    config.service(balance_page).service(commit_balance);
+}
+
+/// A small group that projects can be placed in.
+#[derive(Clone, Debug)]
+struct GroupSlot {
+    small_group_id: i64,
+    /// Small groups share students in proportion to their number of mentors. Small groups
+    /// without mentors count as having one.
+    weight: usize,
+}
+
+/// A project to place in a small group.
+#[derive(Clone, Debug)]
+struct ProjectSize {
+    project_id: i64,
+    /// The number of students enrolled in the project.
+    members: usize,
+    /// The small group to keep the project in, if possible.
+    preferred: Option<i64>,
+}
+
+/// Propose a small group for every project. Projects are kept in their preferred small group
+/// unless it would get too far past its share of students or past the maximum size. The rest
+/// are placed largest first in the small group with the lowest number of students per mentor
+/// that they fit in. Projects that do not fit anywhere go to the least full small group.
+fn propose(groups: &[GroupSlot], projects: &[ProjectSize], max_size: usize) -> HashMap<i64, i64> {
+    let mut assignments: HashMap<i64, i64> = HashMap::new();
+    if groups.is_empty() {
+        return assignments;
+    }
+
+    let total_members: usize = projects.iter().map(|project| project.members).sum();
+    let total_weight: usize = groups.iter().map(|group| group.weight).sum();
+
+    // The most students each small group should have to keep projects in it.
+    let keep_limits: HashMap<i64, usize> = groups
+        .iter()
+        .map(|group| {
+            let share: f64 = total_members as f64 * group.weight as f64 / total_weight as f64;
+            let limit: usize = (share * (1.0 + KEEP_TOLERANCE)).ceil() as usize;
+            (group.small_group_id, limit.min(max_size))
+        })
+        .collect();
+
+    let mut loads: HashMap<i64, usize> = groups
+        .iter()
+        .map(|group| (group.small_group_id, 0))
+        .collect();
+
+    // Place the largest projects first.
+    let mut sorted: Vec<&ProjectSize> = projects.iter().collect();
+    sorted.sort_by(|a, b| {
+        b.members
+            .cmp(&a.members)
+            .then(a.project_id.cmp(&b.project_id))
+    });
+
+    // Keep projects in their preferred small groups where possible. A project always fits
+    // in an empty small group.
+    for project in sorted.iter() {
+        if let Some(preferred) = project.preferred {
+            if let Some(load) = loads.get_mut(&preferred) {
+                if *load == 0 || *load + project.members <= keep_limits[&preferred] {
+                    *load += project.members;
+                    assignments.insert(project.project_id, preferred);
+                }
+            }
+        }
+    }
+
+    // Place the rest of the projects.
+    let remaining: Vec<&ProjectSize> = sorted
+        .into_iter()
+        .filter(|project| !assignments.contains_key(&project.project_id))
+        .collect();
+    for project in remaining {
+        // The number of students per mentor in a small group with this project.
+        let ratio = |group: &GroupSlot| -> f64 {
+            (loads[&group.small_group_id] + project.members) as f64 / group.weight as f64
+        };
+
+        let best: &GroupSlot = groups
+            .iter()
+            .filter(|group| loads[&group.small_group_id] + project.members <= max_size)
+            .min_by(|a, b| ratio(a).partial_cmp(&ratio(b)).unwrap())
+            // Nothing fits, so use the least full small group.
+            .unwrap_or_else(|| {
+                groups
+                    .iter()
+                    .min_by_key(|group| loads[&group.small_group_id])
+                    .unwrap()
+            });
+
+        *loads.get_mut(&best.small_group_id).unwrap() += project.members;
+        assignments.insert(project.project_id, best.small_group_id);
+    }
+
+    return assignments;
+}
+
+/// Get the projects and small groups of a semester, or an error if the semester does not exist.
+async fn get_semester(
+    semester_id: &str,
+) -> Result<small_group_balance::ResponseData, TelescopeError> {
+    let data = SmallGroupBalance::get(semester_id.to_string()).await?;
+    if data.semester.is_none() {
+        return Err(TelescopeError::resource_not_found(
+            "Semester Not Found",
+            "Could not find a semester for this ID.",
+        ));
+    }
+    if data.small_groups.is_empty() {
+        return Err(TelescopeError::BadRequest {
+            header: "No Small Groups".into(),
+            message: "Create the small groups for this semester before balancing them.".into(),
+            show_status_code: false,
+        });
+    }
+    return Ok(data);
+}
+
+/// Query parameters for the balancing proposal.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct BalanceQuery {
+    /// The maximum number of students in a small group.
+    max_size: Option<usize>,
+}
+
+/// Page proposing a small group for every project in a semester.
+#[get("/semesters/small_groups/{semester_id}/balance")]
+async fn balance_page(
+    req: HttpRequest,
+    Path(semester_id): Path<String>,
+    Query(query): Query<BalanceQuery>,
+) -> Result<Page, TelescopeError> {
+    let data = get_semester(semester_id.as_str()).await?;
+    let semester = data.semester.as_ref().unwrap();
+
+    // Find the small group each project was in last semester, by small group title.
+    let titles: HashMap<String, i64> = data
+        .small_groups
+        .iter()
+        .map(|group| (group.title.to_lowercase(), group.small_group_id))
+        .collect();
+    let mut previous: HashMap<i64, i64> = HashMap::new();
+    let mut previous_semester = None;
+    if let Some(semester) = PreviousSmallGroups::get(semester.start_date).await? {
+        for group in semester.small_groups.iter() {
+            if let Some(small_group_id) = titles.get(&group.title.to_lowercase()) {
+                for project in group.small_group_projects.iter() {
+                    previous.insert(project.project_id, *small_group_id);
+                }
+            }
+        }
+        previous_semester = Some(semester.title);
+    }
+
+    // Projects already in a small group this semester stay there if possible. Otherwise
+    // projects stay in the small group they were in last semester.
+    let projects: Vec<ProjectSize> = data
+        .projects
+        .iter()
+        .map(|project| ProjectSize {
+            project_id: project.project_id,
+            members: project
+                .enrollments_aggregate
+                .aggregate
+                .as_ref()
+                .map(|aggregate| aggregate.count)
+                .unwrap_or(0) as usize,
+            preferred: project
+                .small_group_projects
+                .first()
+                .map(|group| group.small_group_id)
+                .or(previous.get(&project.project_id).copied()),
+        })
+        .collect();
+
+    let groups: Vec<GroupSlot> = data
+        .small_groups
+        .iter()
+        .map(|group| GroupSlot {
+            small_group_id: group.small_group_id,
+            weight: group
+                .small_group_mentors_aggregate
+                .aggregate
+                .as_ref()
+                .map(|aggregate| aggregate.count)
+                .unwrap_or(0)
+                .max(1) as usize,
+        })
+        .collect();
+
+    let total_members: usize = projects.iter().map(|project| project.members).sum();
+    let max_size: usize = query.max_size.filter(|size| *size > 0).unwrap_or_else(|| {
+        let average: f64 = total_members as f64 / groups.len() as f64;
+        (average * DEFAULT_MAX_SIZE_FACTOR).ceil().max(1.0) as usize
+    });
+
+    let assignments: HashMap<i64, i64> = propose(&groups, &projects, max_size);
+
+    // The number of students each small group has in the proposal.
+    let mut totals: HashMap<i64, usize> = HashMap::new();
+    for project in projects.iter() {
+        *totals.entry(assignments[&project.project_id]).or_insert(0) += project.members;
+    }
+
+    let group_values: Vec<_> = data
+        .small_groups
+        .iter()
+        .zip(groups.iter())
+        .map(|(group, slot)| {
+            let total: usize = totals.get(&group.small_group_id).copied().unwrap_or(0);
+            json!({
+                "small_group_id": group.small_group_id,
+                "title": group.title,
+                "mentors": slot.weight,
+                "total": total,
+                "is_over": total > max_size,
+            })
+        })
+        .collect();
+
+    let project_values: Vec<_> = data
+        .projects
+        .iter()
+        .zip(projects.iter())
+        .map(|(project, size)| {
+            let proposed: i64 = assignments[&project.project_id];
+            json!({
+                "project_id": project.project_id,
+                "title": project.title,
+                "members": size.members,
+                "current": project.small_group_projects.first().map(|group| group.small_group_id),
+                "previous": previous.get(&project.project_id),
+                "proposed": proposed,
+                "is_moved": size.preferred.map(|preferred| preferred != proposed).unwrap_or(false),
+            })
+        })
+        .collect();
+
+    let mut template = Template::new(TEMPLATE_PATH);
+    template.fields = json!({
+        "semester": semester,
+        "previous_semester": previous_semester,
+        "max_size": max_size,
+        "total_members": total_members,
+        "groups": group_values,
+        "projects": project_values,
+    });
+    return template.in_page(&req, "Balance Small Groups").await;
+}
+
+/// Endpoint to save the reviewed proposal. The form maps `project_{id}` to the ID of the small
+/// group for that project, or an empty string for no small group.
+#[post("/semesters/small_groups/{semester_id}/balance")]
+async fn commit_balance(
+    Path(semester_id): Path<String>,
+    Form(form): Form<HashMap<String, String>>,
+) -> Result<HttpResponse, TelescopeError> {
+    let data = get_semester(semester_id.as_str()).await?;
+
+    let malformed = || TelescopeError::BadRequest {
+        header: "Malformed Small Group Assignments".into(),
+        message: "Could not read the submitted small group for every project.".into(),
+        show_status_code: false,
+    };
+
+    // Only projects and small groups from this semester can be assigned.
+    let mut assignments: Vec<(i64, Option<i64>)> = Vec::new();
+    for project in data.projects.iter() {
+        let field: String = format!("project_{}", project.project_id);
+        let small_group_id: Option<i64> = match form.get(&field).map(|value| value.trim()) {
+            None => continue,
+            Some("") => None,
+            Some(value) => Some(value.parse::<i64>().map_err(|_| malformed())?),
+        };
+
+        if let Some(small_group_id) = small_group_id {
+            if !data
+                .small_groups
+                .iter()
+                .any(|group| group.small_group_id == small_group_id)
+            {
+                return Err(malformed());
+            }
+        }
+
+        assignments.push((project.project_id, small_group_id));
+    }
+
+    AssignSmallGroupProjects::execute(semester_id.clone(), assignments).await?;
+
+    return Ok(HttpResponse::Found()
+        .header(
+            LOCATION,
+            format!("/coordinate/semesters/small_groups/{}", semester_id),
+        )
+        .finish());
+}
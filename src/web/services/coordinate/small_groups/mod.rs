@@ -0,0 +1,358 @@
+//! Small group management services for coordinators.
+//!
+//! Coordinators create the small groups of a semester (or copy them from the previous semester)
+//! and assign projects and mentors to them, by hand or with a balancing proposal.
+
+use crate::api::rcos::small_groups::{
+    semester_small_groups, AddSmallGroupMentor, AddSmallGroupProject, CloneSmallGroups,
+    CreateSmallGroup, DeleteSmallGroup, EditSmallGroup, PreviousSmallGroups,
+    RemoveSmallGroupMentor, RemoveSmallGroupProject, SemesterSmallGroups,
+};
+use crate::error::TelescopeError;
+use crate::templates::page::Page;
+use crate::templates::Template;
+use actix_web::http::header::LOCATION;
+use actix_web::web::{Form, Path, ServiceConfig};
+use actix_web::{HttpRequest, HttpResponse};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+mod balance;
+
+/// The handlebars template for the small group management page.
+const TEMPLATE_PATH: &'static str = "coordinate/small_groups/semester";
+
+/// Register small group management services. These are registered in the coordinator scope.
+pub fn register(config: &mut ServiceConfig) {
+    config
+        .service(groups_page)
+        .service(create)
+        .service(clone_previous)
+        .service(edit)
+        .service(delete)
+        .service(add_project)
+        .service(remove_project)
+        .service(add_mentor)
+        .service(remove_mentor);
+
+    balance::register(config);
+}
+
+/// Get the small groups of a semester, or an error if the semester does not exist.
+async fn get_semester(
+    semester_id: &str,
+) -> Result<semester_small_groups::ResponseData, TelescopeError> {
+    let data = SemesterSmallGroups::get(semester_id.to_string()).await?;
+    if data.semester.is_none() {
+        return Err(TelescopeError::resource_not_found(
+            "Semester Not Found",
+            "Could not find a semester for this ID.",
+        ));
+    }
+    return Ok(data);
+}
+
+/// Make the small group management page for a semester.
+fn make_page(data: &semester_small_groups::ResponseData) -> Template {
+    // Projects that are not in any small group yet.
+    let unassigned: Vec<_> = data
+        .projects
+        .iter()
+        .filter(|project| project.small_group_projects.is_empty())
+        .collect();
+
+    let mut template = Template::new(TEMPLATE_PATH);
+    template.fields = json!({
+        "data": data,
+        "unassigned_projects": unassigned,
+    });
+    return template;
+}
+
+/// Redirect to a small group on the management page of its semester.
+fn redirect(semester_id: &str, small_group_id: Option<i64>) -> HttpResponse {
+    let location: String = match small_group_id {
+        Some(id) => format!(
+            "/coordinate/semesters/small_groups/{}#small-group-{}",
+            semester_id, id
+        ),
+        None => format!("/coordinate/semesters/small_groups/{}", semester_id),
+    };
+
+    HttpResponse::Found().header(LOCATION, location).finish()
+}
+
+/// Error for small group IDs that do not exist.
+fn small_group_not_found() -> TelescopeError {
+    TelescopeError::resource_not_found(
+        "Small Group Not Found",
+        "Could not find a small group for this ID.",
+    )
+}
+
+/// Get a small group of a semester, or an error if it is not in that semester.
+fn find_small_group(
+    data: &semester_small_groups::ResponseData,
+    small_group_id: i64,
+) -> Result<&semester_small_groups::SemesterSmallGroupsSmallGroups, TelescopeError> {
+    data.small_groups
+        .iter()
+        .find(|small_group| small_group.small_group_id == small_group_id)
+        .ok_or(small_group_not_found())
+}
+
+/// Page to manage the small groups of a semester.
+#[get("/semesters/small_groups/{semester_id}")]
+async fn groups_page(
+    req: HttpRequest,
+    Path(semester_id): Path<String>,
+) -> Result<Page, TelescopeError> {
+    let data = get_semester(semester_id.as_str()).await?;
+    make_page(&data).in_page(&req, "Small Groups").await
+}
+
+/// Form submitted by coordinators to create or edit a small group.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct SmallGroupForm {
+    title: String,
+
+    #[serde(default)]
+    location: String,
+}
+
+impl SmallGroupForm {
+    /// Get the trimmed location, if there is one.
+    fn location(&self) -> Option<String> {
+        Some(self.location.trim().to_string()).filter(|location| !location.is_empty())
+    }
+
+    /// Check that the title is not empty and not used by another small group in the semester.
+    /// Return an issue with the title if there is one.
+    fn title_issue(
+        &self,
+        data: &semester_small_groups::ResponseData,
+        small_group_id: Option<i64>,
+    ) -> Option<&'static str> {
+        let title: &str = self.title.trim();
+        if title.is_empty() {
+            return Some("Small groups need a title.");
+        }
+
+        let is_taken: bool = data.small_groups.iter().any(|small_group| {
+            Some(small_group.small_group_id) != small_group_id
+                && small_group.title.eq_ignore_ascii_case(title)
+        });
+        if is_taken {
+            return Some("There is already a small group with this title this semester.");
+        }
+
+        return None;
+    }
+}
+
+/// Endpoint to create a small group.
+#[post("/semesters/small_groups/{semester_id}/create")]
+async fn create(
+    req: HttpRequest,
+    Path(semester_id): Path<String>,
+    Form(form): Form<SmallGroupForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    let data = get_semester(semester_id.as_str()).await?;
+
+    if let Some(issue) = form.title_issue(&data, None) {
+        let mut return_form: Template = make_page(&data);
+        return_form["selections"] = json!(&form);
+        return_form["issues"]["title"] = json!(issue);
+        let page = return_form.in_page(&req, "Small Groups").await?;
+        return Err(TelescopeError::InvalidForm(page));
+    }
+
+    let small_group_id: i64 = CreateSmallGroup::execute(
+        semester_id.clone(),
+        form.title.trim().to_string(),
+        form.location(),
+    )
+    .await?
+    .ok_or(TelescopeError::ise(
+        "Small group creation did not return an ID.",
+    ))?;
+
+    return Ok(redirect(semester_id.as_str(), Some(small_group_id)));
+}
+
+/// Endpoint to copy the small groups of the previous semester into a semester.
+#[post("/semesters/small_groups/{semester_id}/clone")]
+async fn clone_previous(Path(semester_id): Path<String>) -> Result<HttpResponse, TelescopeError> {
+    let data = get_semester(semester_id.as_str()).await?;
+    let start_date = data.semester.as_ref().unwrap().start_date;
+
+    let previous =
+        PreviousSmallGroups::get(start_date)
+            .await?
+            .ok_or(TelescopeError::BadRequest {
+                header: "No Previous Small Groups".into(),
+                message: "None of the semesters before this one have small groups.".into(),
+                show_status_code: false,
+            })?;
+
+    // Skip the small groups that already exist, and projects and mentors that are not enrolled.
+    let existing_titles: HashSet<String> = data
+        .small_groups
+        .iter()
+        .map(|small_group| small_group.title.to_lowercase())
+        .collect();
+    let enrolled_projects: HashSet<i64> = data
+        .projects
+        .iter()
+        .map(|project| project.project_id)
+        .collect();
+    let enrolled: HashSet<Uuid> = data
+        .students
+        .iter()
+        .map(|enrollment| enrollment.user.id)
+        .collect();
+
+    CloneSmallGroups::execute(
+        semester_id.clone(),
+        &previous,
+        &existing_titles,
+        &enrolled_projects,
+        &enrolled,
+    )
+    .await?;
+
+    return Ok(redirect(semester_id.as_str(), None));
+}
+
+/// Endpoint to change the title and location of a small group.
+#[post("/semesters/small_groups/{semester_id}/{small_group_id}/edit")]
+async fn edit(
+    Path((semester_id, small_group_id)): Path<(String, i64)>,
+    Form(form): Form<SmallGroupForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    let data = get_semester(semester_id.as_str()).await?;
+    find_small_group(&data, small_group_id)?;
+
+    if let Some(issue) = form.title_issue(&data, Some(small_group_id)) {
+        return Err(TelescopeError::BadRequest {
+            header: "Invalid Small Group Title".into(),
+            message: issue.into(),
+            show_status_code: false,
+        });
+    }
+
+    EditSmallGroup::execute(
+        small_group_id,
+        form.title.trim().to_string(),
+        form.location(),
+    )
+    .await?
+    .ok_or(small_group_not_found())?;
+
+    return Ok(redirect(semester_id.as_str(), Some(small_group_id)));
+}
+
+/// Endpoint to delete a small group. Small groups with Discord channels, categories, or roles
+/// cannot be deleted.
+#[post("/semesters/small_groups/{semester_id}/{small_group_id}/delete")]
+async fn delete(
+    Path((semester_id, small_group_id)): Path<(String, i64)>,
+) -> Result<HttpResponse, TelescopeError> {
+    let data = get_semester(semester_id.as_str()).await?;
+    let small_group = find_small_group(&data, small_group_id)?;
+
+    if !small_group.small_group_channels.is_empty()
+        || !small_group.small_group_categories.is_empty()
+        || small_group.small_group_role.is_some()
+    {
+        return Err(TelescopeError::BadRequest {
+            header: "Small Group Has Discord Channels".into(),
+            message: "Small groups with Discord channels, categories, or roles cannot be deleted."
+                .into(),
+            show_status_code: false,
+        });
+    }
+
+    DeleteSmallGroup::execute(small_group_id)
+        .await?
+        .ok_or(small_group_not_found())?;
+
+    return Ok(redirect(semester_id.as_str(), None));
+}
+
+/// Form submitted by coordinators to add a project to or remove a project from a small group.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct ProjectForm {
+    project_id: i64,
+}
+
+/// Endpoint to add a project to a small group.
+#[post("/semesters/small_groups/{semester_id}/{small_group_id}/projects/add")]
+async fn add_project(
+    Path((semester_id, small_group_id)): Path<(String, i64)>,
+    Form(form): Form<ProjectForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    let data = get_semester(semester_id.as_str()).await?;
+    find_small_group(&data, small_group_id)?;
+
+    // Only projects with students enrolled this semester can be added.
+    if !data
+        .projects
+        .iter()
+        .any(|project| project.project_id == form.project_id)
+    {
+        return Err(TelescopeError::BadRequest {
+            header: "Project Not Enrolled".into(),
+            message: "This project has no students enrolled this semester.".into(),
+            show_status_code: false,
+        });
+    }
+
+    AddSmallGroupProject::execute(small_group_id, form.project_id).await?;
+    return Ok(redirect(semester_id.as_str(), Some(small_group_id)));
+}
+
+/// Endpoint to remove a project from a small group.
+#[post("/semesters/small_groups/{semester_id}/{small_group_id}/projects/remove")]
+async fn remove_project(
+    Path((semester_id, small_group_id)): Path<(String, i64)>,
+    Form(form): Form<ProjectForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    let data = get_semester(semester_id.as_str()).await?;
+    find_small_group(&data, small_group_id)?;
+
+    RemoveSmallGroupProject::execute(small_group_id, form.project_id).await?;
+    return Ok(redirect(semester_id.as_str(), Some(small_group_id)));
+}
+
+/// Form submitted by coordinators to add a mentor to or remove a mentor from a small group.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct MentorForm {
+    user_id: Uuid,
+}
+
+/// Endpoint to add a mentor to a small group.
+#[post("/semesters/small_groups/{semester_id}/{small_group_id}/mentors/add")]
+async fn add_mentor(
+    Path((semester_id, small_group_id)): Path<(String, i64)>,
+    Form(form): Form<MentorForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    let data = get_semester(semester_id.as_str()).await?;
+    find_small_group(&data, small_group_id)?;
+
+    AddSmallGroupMentor::execute(small_group_id, form.user_id).await?;
+    return Ok(redirect(semester_id.as_str(), Some(small_group_id)));
+}
+
+/// Endpoint to remove a mentor from a small group.
+#[post("/semesters/small_groups/{semester_id}/{small_group_id}/mentors/remove")]
+async fn remove_mentor(
+    Path((semester_id, small_group_id)): Path<(String, i64)>,
+    Form(form): Form<MentorForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    let data = get_semester(semester_id.as_str()).await?;
+    find_small_group(&data, small_group_id)?;
+
+    RemoveSmallGroupMentor::execute(small_group_id, form.user_id).await?;
+    return Ok(redirect(semester_id.as_str(), Some(small_group_id)));
+}
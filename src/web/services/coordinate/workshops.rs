@@ -0,0 +1,158 @@
+//! Workshop proposal review services for coordinators.
+//!
+//! Approving a proposal schedules it as a bonus session hosted by the mentor who proposed it,
+//! at whichever of their preferred times the coordinator picks.
+
+use crate::api::rcos::workshops::{
+    approve_workshop_proposal, ApproveWorkshopProposal, ReviewWorkshopProposal, WorkshopProposal,
+    WorkshopProposals,
+};
+use crate::error::TelescopeError;
+use crate::templates::page::Page;
+use crate::templates::Template;
+use crate::web::services::auth::identity::AuthenticationCookie;
+use actix_web::http::header::LOCATION;
+use actix_web::web::{Form, Path, ServiceConfig};
+use actix_web::{HttpRequest, HttpResponse};
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+/// The handlebars template for the workshop proposal review queue.
+const TEMPLATE_PATH: &'static str = "coordinate/workshops";
+
+/// The default length of a workshop in minutes.
+const DEFAULT_DURATION: i64 = 60;
+
+/// Register workshop proposal review services. These are registered in the coordinator scope.
+pub fn register(config: &mut ServiceConfig) {
+    config.service(queue).service(review);
+}
+
+/// Page listing the workshop proposals for the current semesters.
+#[get("/workshops")]
+async fn queue(req: HttpRequest) -> Result<Page, TelescopeError> {
+    let mut template = Template::new(TEMPLATE_PATH);
+    template.fields = json!({
+        "proposals": WorkshopProposals::get().await?,
+        "default_duration": DEFAULT_DURATION,
+    });
+    return template.in_page(&req, "Workshop Proposals").await;
+}
+
+/// Form submitted by coordinators to approve or reject a workshop proposal.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct ReviewForm {
+    /// Either "approve" or "reject".
+    decision: String,
+
+    /// Which preferred time to schedule the workshop at. Either "first", "second", or "third".
+    /// Ignored for rejections.
+    #[serde(default)]
+    slot: String,
+
+    /// Length of the workshop in minutes.
+    duration: Option<i64>,
+
+    /// Optional location of the workshop.
+    #[serde(default)]
+    location: String,
+
+    #[serde(default)]
+    reviewer_comments: String,
+}
+
+/// Endpoint to approve or reject a workshop proposal.
+#[post("/workshops/{workshop_proposal_id}/review")]
+async fn review(
+    auth: AuthenticationCookie,
+    Path(workshop_proposal_id): Path<i64>,
+    Form(form): Form<ReviewForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    let reviewer_id: Uuid = auth.get_user_id_or_error().await?;
+
+    let proposal = WorkshopProposal::get(workshop_proposal_id).await?.ok_or(
+        TelescopeError::resource_not_found(
+            "Workshop Proposal Not Found",
+            "Could not find a workshop proposal for this ID.",
+        ),
+    )?;
+
+    // Approved proposals already have a meeting.
+    if proposal.is_approved == Some(true) {
+        return Err(TelescopeError::BadRequest {
+            header: "Proposal Already Approved".into(),
+            message: "This workshop was already approved and scheduled.".into(),
+            show_status_code: false,
+        });
+    }
+
+    let is_approved: bool = match form.decision.as_str() {
+        "approve" => true,
+        "reject" => false,
+        _ => {
+            return Err(TelescopeError::BadRequest {
+                header: "Invalid Decision".into(),
+                message: "Workshop proposals can only be approved or rejected.".into(),
+                show_status_code: false,
+            })
+        }
+    };
+
+    let comments: Option<String> = (!form.reviewer_comments.trim().is_empty())
+        .then(|| form.reviewer_comments.trim().to_string());
+
+    // Schedule the workshop as a bonus session if it was approved. The review and the meeting
+    // are recorded in the same mutation.
+    let reviewed: Option<()> = if is_approved {
+        let start: DateTime<Utc> = match form.slot.as_str() {
+            "first" => proposal.first_choice_at,
+            "second" => proposal.second_choice_at,
+            "third" => proposal.third_choice_at,
+            _ => {
+                return Err(TelescopeError::BadRequest {
+                    header: "Invalid Time Slot".into(),
+                    message: "Please pick one of the proposed times.".into(),
+                    show_status_code: false,
+                })
+            }
+        };
+
+        let duration: i64 = form.duration.unwrap_or(DEFAULT_DURATION);
+        if duration <= 0 {
+            return Err(TelescopeError::BadRequest {
+                header: "Invalid Duration".into(),
+                message: "Workshops must last at least one minute.".into(),
+                show_status_code: false,
+            });
+        }
+
+        let location: Option<String> =
+            (!form.location.trim().is_empty()).then(|| form.location.trim().to_string());
+
+        ApproveWorkshopProposal::execute(approve_workshop_proposal::Variables {
+            workshop_proposal_id,
+            reviewer_id,
+            reviewer_comments: comments,
+            host: proposal.user_id,
+            title: proposal.title.clone(),
+            start,
+            end: start + Duration::minutes(duration),
+            description: proposal.topic.clone(),
+            location,
+            semester_id: proposal.semester_id.clone(),
+        })
+        .await?
+    } else {
+        ReviewWorkshopProposal::execute(workshop_proposal_id, reviewer_id, false, comments).await?
+    };
+
+    reviewed.ok_or(TelescopeError::resource_not_found(
+        "Workshop Proposal Not Found",
+        "Could not find a workshop proposal for this ID.",
+    ))?;
+
+    // Send the coordinator back to the queue.
+    return Ok(HttpResponse::Found()
+        .header(LOCATION, "/coordinate/workshops")
+        .finish());
+}
@@ -0,0 +1,124 @@
+//! Final grade appeal services.
+//!
+//! Students can appeal their final grade for a semester once it has ended. Appeals are triaged
+//! by faculty advisors from the admin panel.
+
+use crate::api::rcos::grade_appeals::{SubmitGradeAppeal, UserGradeAppeals};
+use crate::error::TelescopeError;
+use crate::templates::page::Page;
+use crate::templates::Template;
+use crate::web::services::auth::identity::AuthenticationCookie;
+use actix_web::http::header::LOCATION;
+use actix_web::web::{Form, ServiceConfig};
+use actix_web::{HttpRequest, HttpResponse};
+use uuid::Uuid;
+
+/// The handlebars template for the grade appeal form.
+const TEMPLATE_PATH: &'static str = "engage/grade_appeals";
+
+/// Register grade appeal services.
+pub fn register(config: &mut ServiceConfig) {
+    config.service(appeals_page).service(submit_appeal);
+}
+
+/// Create the grade appeal form for a user.
+async fn appeal_form(user_id: Uuid) -> Result<Template, TelescopeError> {
+    let mut form = Template::new(TEMPLATE_PATH);
+    form.fields = json!({
+        "enrollments": UserGradeAppeals::get(user_id).await?,
+    });
+    return Ok(form);
+}
+
+/// Page to view and file final grade appeals.
+#[get("/engage/grade_appeals")]
+async fn appeals_page(
+    req: HttpRequest,
+    auth: AuthenticationCookie,
+) -> Result<Page, TelescopeError> {
+    let user_id: Uuid = auth.get_user_id_or_error().await?;
+    return appeal_form(user_id)
+        .await?
+        .in_page(&req, "Grade Appeals")
+        .await;
+}
+
+/// Form submitted by students to appeal a final grade.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct AppealForm {
+    /// The semester whose grade is being appealed.
+    semester: String,
+    expected_grade: String,
+    reason: String,
+}
+
+/// Endpoint to file a final grade appeal.
+#[post("/engage/grade_appeals")]
+async fn submit_appeal(
+    req: HttpRequest,
+    auth: AuthenticationCookie,
+    Form(form): Form<AppealForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    let user_id: Uuid = auth.get_user_id_or_error().await?;
+
+    // Create a form to send back if there are any issues.
+    let mut return_form: Template = appeal_form(user_id).await?;
+    return_form["selections"] = json!(&form);
+
+    // Find the enrollment for the selected semester. Only semesters that have ended are listed.
+    let enrollment = return_form["enrollments"]
+        .as_array()
+        .and_then(|enrollments| {
+            enrollments
+                .iter()
+                .find(|e| e["semester"]["semester_id"] == form.semester.as_str())
+        })
+        .cloned();
+
+    match enrollment {
+        None => {
+            return_form["issues"]["semester"] = json!(
+                "You can only appeal grades for semesters you were enrolled in that have ended."
+            );
+        }
+        // Handled appeals are final.
+        Some(enrollment) if enrollment["final_grade_appeals"][0]["is_handled"] == true => {
+            return_form["issues"]["semester"] =
+                json!("Your appeal for this semester was already handled.");
+        }
+        _ => {}
+    }
+
+    if form.expected_grade.trim().is_empty() {
+        return_form["issues"]["expected_grade"] = json!("Please enter the grade you expected.");
+    }
+
+    if form.reason.trim().is_empty() {
+        return_form["issues"]["reason"] = json!("Please explain why you are appealing.");
+    }
+
+    // Return the form if there are any issues.
+    if !return_form["issues"].is_null() {
+        let page = return_form.in_page(&req, "Grade Appeals").await?;
+        return Err(TelescopeError::InvalidForm(page));
+    }
+
+    SubmitGradeAppeal::execute(
+        form.semester,
+        user_id,
+        form.expected_grade.trim().to_string(),
+        form.reason.trim().to_string(),
+    )
+    .await?
+    // The appeal may have been handled since the form was loaded.
+    .ok_or(TelescopeError::BadRequest {
+        header: "Appeal Already Handled".into(),
+        message: "Your appeal for this semester was already handled.".into(),
+        show_status_code: false,
+    })?;
+
+    // Send the student back to the form, which shows the status of their appeal.
+    return Ok(HttpResponse::Found()
+        .header(LOCATION, "/engage/grade_appeals")
+        .finish());
+}
@@ -0,0 +1,79 @@
+//! Services for the student engagement panel.
+
+use crate::api::rcos::status_updates::current::CurrentStatusUpdates;
+use crate::api::rcos::status_updates::is_open;
+use crate::api::rcos::users::navbar_auth::Authentication;
+use crate::error::TelescopeError;
+use crate::templates::page::Page;
+use crate::templates::Template;
+use crate::web::middlewares::authorization::AuthorizationResult;
+use crate::web::services::auth::identity::AuthenticationCookie;
+use actix_web::web::ServiceConfig;
+use actix_web::HttpRequest;
+use futures::future::LocalBoxFuture;
+use uuid::Uuid;
+
+mod grade_appeals;
+mod pay_requests;
+mod presentations;
+mod status_updates;
+
+/// Check that a user can grade student work. This is current mentors, current coordinators,
+/// and admins.
+fn grader_authorization(user_id: Uuid) -> LocalBoxFuture<'static, AuthorizationResult> {
+    Box::pin(async move {
+        let auth = Authentication::get(user_id).await?;
+        if auth.is_mentoring() || auth.is_coordinating() || auth.is_admin() {
+            Ok(())
+        } else {
+            Err(TelescopeError::Forbidden)
+        }
+    })
+}
+
+/// Register engagement panel services.
+pub fn register(config: &mut ServiceConfig) {
+    config.service(index);
+
+    // Status update services.
+    status_updates::register(config);
+
+    // Pay request services.
+    pay_requests::register(config);
+
+    // Final grade appeal services.
+    grade_appeals::register(config);
+
+    // Presentation grading services.
+    presentations::register(config);
+}
+
+/// Engagement panel index page.
+#[get("/engage")]
+async fn index(req: HttpRequest, auth: AuthenticationCookie) -> Result<Page, TelescopeError> {
+    // Get the viewer's user ID.
+    let user_id: Uuid = auth.get_user_id_or_error().await?;
+
+    // Get the status updates for the current semesters and the viewer's permissions.
+    let status_updates = CurrentStatusUpdates::get(user_id).await?;
+    let viewer = Authentication::get(user_id).await?;
+
+    // Mark which status update windows are open.
+    let mut data = json!(&status_updates);
+    for (i, semester) in status_updates.semesters.iter().enumerate() {
+        for (j, status_update) in semester.status_updates.iter().enumerate() {
+            data["semesters"][i]["status_updates"][j]["is_open"] = json!(is_open(
+                status_update.open_date_time,
+                status_update.close_date_time
+            ));
+        }
+    }
+
+    let mut template = Template::new("engage/index");
+    template.fields = json!({
+        "data": data,
+        "can_create": viewer.is_coordinating() || viewer.is_admin(),
+        "can_grade": viewer.is_mentoring() || viewer.is_coordinating() || viewer.is_admin(),
+    });
+    return template.in_page(&req, "Engage").await;
+}
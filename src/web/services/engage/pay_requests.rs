@@ -0,0 +1,106 @@
+//! Pay request services.
+//!
+//! Enrolled students can ask to be paid instead of receiving credit for a semester. Requests are
+//! reviewed from the admin panel.
+
+use crate::api::rcos::pay_requests::{SubmitPayRequest, UserPayRequests};
+use crate::error::TelescopeError;
+use crate::templates::page::Page;
+use crate::templates::Template;
+use crate::web::services::auth::identity::AuthenticationCookie;
+use actix_web::http::header::LOCATION;
+use actix_web::web::{Form, ServiceConfig};
+use actix_web::{HttpRequest, HttpResponse};
+use uuid::Uuid;
+
+/// The handlebars template for the pay request form.
+const TEMPLATE_PATH: &'static str = "engage/pay_request";
+
+/// Register pay request services.
+pub fn register(config: &mut ServiceConfig) {
+    config.service(pay_request_page).service(submit_pay_request);
+}
+
+/// Create the pay request form for a user.
+async fn pay_request_form(user_id: Uuid) -> Result<Template, TelescopeError> {
+    let mut form = Template::new(TEMPLATE_PATH);
+    form.fields = json!({
+        "enrollments": UserPayRequests::get(user_id).await?,
+    });
+    return Ok(form);
+}
+
+/// Page to request pay instead of credit.
+#[get("/engage/pay_request")]
+async fn pay_request_page(
+    req: HttpRequest,
+    auth: AuthenticationCookie,
+) -> Result<Page, TelescopeError> {
+    let user_id: Uuid = auth.get_user_id_or_error().await?;
+    return pay_request_form(user_id)
+        .await?
+        .in_page(&req, "Pay Request")
+        .await;
+}
+
+/// Form submitted by students to request pay.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct PayRequestForm {
+    /// The semester the student wants to be paid for.
+    semester: String,
+    reason: String,
+}
+
+/// Endpoint to request pay instead of credit.
+#[post("/engage/pay_request")]
+async fn submit_pay_request(
+    req: HttpRequest,
+    auth: AuthenticationCookie,
+    Form(form): Form<PayRequestForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    let user_id: Uuid = auth.get_user_id_or_error().await?;
+
+    // Create a form to send back if there are any issues.
+    let mut return_form: Template = pay_request_form(user_id).await?;
+    return_form["selections"] = json!(&form);
+
+    // Find the enrollment for the selected semester.
+    let enrollment = return_form["enrollments"]
+        .as_array()
+        .and_then(|enrollments| {
+            enrollments
+                .iter()
+                .find(|e| e["semester"]["semester_id"] == form.semester.as_str())
+        })
+        .cloned();
+
+    match enrollment {
+        None => {
+            return_form["issues"]["semester"] =
+                json!("Please select a current semester you are enrolled in.");
+        }
+        // Reviewed requests are final.
+        Some(enrollment) if !enrollment["pay_request"]["is_approved"].is_null() => {
+            return_form["issues"]["semester"] =
+                json!("Your request for this semester was already reviewed.");
+        }
+        _ => {}
+    }
+
+    if form.reason.trim().is_empty() {
+        return_form["issues"]["reason"] = json!("Please explain why you are requesting pay.");
+    }
+
+    // Return the form if there are any issues.
+    if !return_form["issues"].is_null() {
+        let page = return_form.in_page(&req, "Pay Request").await?;
+        return Err(TelescopeError::InvalidForm(page));
+    }
+
+    SubmitPayRequest::execute(form.semester, user_id, form.reason.trim().to_string()).await?;
+
+    // Send the student back to the form, which shows the status of their request.
+    return Ok(HttpResponse::Found()
+        .header(LOCATION, "/engage/pay_request")
+        .finish());
+}
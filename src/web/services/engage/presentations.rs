@@ -0,0 +1,195 @@
+//! Project presentation grading services for graders.
+//!
+//! While a semester's presentation grading session is open, mentors, coordinators, and admins
+//! enrolled in the semester score every project against each criterion of the rubric.
+
+use crate::api::rcos::presentations::{
+    presentation_session, PresentationGrader, PresentationSession, SavePresentationGrade,
+};
+use crate::error::TelescopeError;
+use crate::templates::page::Page;
+use crate::templates::Template;
+use crate::web::middlewares::authorization::Authorization;
+use crate::web::services::auth::identity::AuthenticationCookie;
+use crate::web::services::engage::grader_authorization;
+use actix_web::http::header::LOCATION;
+use actix_web::web as aweb;
+use actix_web::web::{Form, Path, ServiceConfig};
+use actix_web::{HttpRequest, HttpResponse};
+use serde_json::Value;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// The handlebars template for graders to score projects.
+const GRADING_TEMPLATE: &'static str = "engage/presentations/grade";
+
+/// Register presentation grading services.
+pub fn register(config: &mut ServiceConfig) {
+    config.service(
+        aweb::scope("/engage/presentations/{semester_id}")
+            .wrap(Authorization::new(grader_authorization))
+            .service(grading_page)
+            .service(grade_project),
+    );
+}
+
+/// Get the grading session of a semester and check that a user can grade in it.
+async fn get_open_session(
+    semester_id: &str,
+    grader_id: Uuid,
+) -> Result<presentation_session::ResponseData, TelescopeError> {
+    let data = PresentationSession::get(semester_id.to_string()).await?;
+    if data.semester.is_none() {
+        return Err(TelescopeError::resource_not_found(
+            "Semester Not Found",
+            "Could not find a semester for this ID.",
+        ));
+    }
+
+    let is_open: bool = data
+        .semester
+        .as_ref()
+        .and_then(|semester| semester.presentation_grading_session.as_ref())
+        .map(|session| session.is_open)
+        .unwrap_or(false);
+    if !is_open {
+        return Err(TelescopeError::BadRequest {
+            header: "Grading Session Not Open".into(),
+            message: "Presentation grading is not open for this semester.".into(),
+            show_status_code: false,
+        });
+    }
+
+    // Grades are tied to the grader's enrollment in the semester.
+    if !PresentationGrader::is_enrolled(semester_id.to_string(), grader_id).await? {
+        return Err(TelescopeError::BadRequest {
+            header: "Not Enrolled".into(),
+            message: "Graders must be enrolled in the semester of the presentations.".into(),
+            show_status_code: false,
+        });
+    }
+
+    return Ok(data);
+}
+
+/// Page for a grader to score every project against the rubric.
+#[get("")]
+async fn grading_page(
+    req: HttpRequest,
+    auth: AuthenticationCookie,
+    Path(semester_id): Path<String>,
+) -> Result<Page, TelescopeError> {
+    let grader_id: Uuid = auth.get_user_id_or_error().await?;
+    let data = get_open_session(semester_id.as_str(), grader_id).await?;
+
+    let criteria = data
+        .semester
+        .as_ref()
+        .and_then(|semester| semester.presentation_grading_session.as_ref())
+        .map(|session| session.criteria.as_slice())
+        .unwrap_or(&[]);
+
+    // Fill in the grader's previous scores for each project.
+    let projects: Vec<Value> = data
+        .projects
+        .iter()
+        .map(|project| {
+            let scores: HashMap<i64, f64> = project
+                .project_presentation_grades
+                .iter()
+                .filter(|grade| grade.grader_id == grader_id)
+                .flat_map(|grade| grade.criterion_scores.iter())
+                .map(|score| (score.criterion_id, score.score))
+                .collect();
+
+            // Scores are strings so that zeros are still filled in by the template.
+            let criteria: Vec<Value> = criteria
+                .iter()
+                .map(|criterion| {
+                    json!({
+                        "criterion_id": criterion.criterion_id,
+                        "title": criterion.title,
+                        "description": criterion.description,
+                        "max_score": criterion.max_score,
+                        "score": scores.get(&criterion.criterion_id).map(|score| score.to_string()),
+                    })
+                })
+                .collect();
+
+            json!({
+                "project_id": project.project_id,
+                "title": project.title,
+                "presentation": project.project_presentations.first(),
+                "is_graded": !scores.is_empty(),
+                "criteria": criteria,
+            })
+        })
+        .collect();
+
+    let mut template = Template::new(GRADING_TEMPLATE);
+    template.fields = json!({
+        "semester": &data.semester,
+        "projects": projects,
+    });
+    return template.in_page(&req, "Grade Presentations").await;
+}
+
+/// Endpoint for a grader to save their scores for a project. The form maps `criterion_{id}`
+/// to the score for each criterion of the rubric.
+#[post("/{project_id}")]
+async fn grade_project(
+    auth: AuthenticationCookie,
+    Path((semester_id, project_id)): Path<(String, i64)>,
+    Form(form): Form<HashMap<String, String>>,
+) -> Result<HttpResponse, TelescopeError> {
+    let grader_id: Uuid = auth.get_user_id_or_error().await?;
+    let data = get_open_session(semester_id.as_str(), grader_id).await?;
+
+    if !data
+        .projects
+        .iter()
+        .any(|project| project.project_id == project_id)
+    {
+        return Err(TelescopeError::resource_not_found(
+            "Project Not Found",
+            "Could not find a project with students enrolled this semester for this ID.",
+        ));
+    }
+
+    let criteria = data
+        .semester
+        .as_ref()
+        .and_then(|semester| semester.presentation_grading_session.as_ref())
+        .map(|session| session.criteria.as_slice())
+        .unwrap_or(&[]);
+
+    // Every criterion needs a score between zero and its maximum.
+    let mut scores: Vec<(i64, f64)> = Vec::with_capacity(criteria.len());
+    for criterion in criteria {
+        let score: f64 = form
+            .get(&format!("criterion_{}", criterion.criterion_id))
+            .and_then(|value| value.trim().parse::<f64>().ok())
+            .filter(|score| *score >= 0.0 && *score <= criterion.max_score as f64)
+            .ok_or(TelescopeError::BadRequest {
+                header: "Invalid Score".into(),
+                message: format!(
+                    "Scores for {} must be between 0 and {}.",
+                    criterion.title, criterion.max_score
+                ),
+                show_status_code: false,
+            })?;
+        scores.push((criterion.criterion_id, score));
+    }
+
+    SavePresentationGrade::execute(semester_id.clone(), project_id, grader_id, scores).await?;
+
+    return Ok(HttpResponse::Found()
+        .header(
+            LOCATION,
+            format!(
+                "/engage/presentations/{}#project-{}",
+                semester_id, project_id
+            ),
+        )
+        .finish());
+}
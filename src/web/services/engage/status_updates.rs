@@ -0,0 +1,330 @@
+//! Status update services.
+//!
+//! Coordinators create status update windows for a semester. While a window is open, enrolled
+//! students can submit (and resubmit) a status update to it. Mentors and coordinators then
+//! grade the submissions and leave comments.
+
+use crate::api::rcos::semesters::current::info::CurrentSemesters;
+use crate::api::rcos::status_updates::create::CreateStatusUpdate;
+use crate::api::rcos::status_updates::get_by_id::{
+    status_update::StatusUpdateStatusUpdate, StatusUpdate,
+};
+use crate::api::rcos::status_updates::grading::{GradeStatusUpdate, StatusUpdateSubmissions};
+use crate::api::rcos::status_updates::submit::SubmitStatusUpdate;
+use crate::error::TelescopeError;
+use crate::templates::page::Page;
+use crate::templates::Template;
+use crate::web::local_timestamp;
+use crate::web::middlewares::authorization::Authorization;
+use crate::web::services::auth::identity::AuthenticationCookie;
+use crate::web::services::coordinate::coordinator_authorization;
+use crate::web::services::engage::grader_authorization;
+use actix_web::http::header::LOCATION;
+use actix_web::web as aweb;
+use actix_web::web::{Form, Path, ServiceConfig};
+use actix_web::{HttpRequest, HttpResponse};
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
+
+/// The handlebars template for the status update creation form.
+const CREATE_TEMPLATE: &'static str = "engage/status_updates/create";
+
+/// The handlebars template for the status update submission form.
+const SUBMIT_TEMPLATE: &'static str = "engage/status_updates/submit";
+
+/// The handlebars template for the status update grading page.
+const GRADING_TEMPLATE: &'static str = "engage/status_updates/grading";
+
+/// Register status update services.
+pub fn register(config: &mut ServiceConfig) {
+    // The creation scope must be registered before the submission services so that "create"
+    // is not matched as a status update ID.
+    config.service(
+        aweb::scope("/engage/status_updates/create")
+            .wrap(Authorization::new(coordinator_authorization))
+            .service(create_page)
+            .service(submit_create),
+    );
+
+    config.service(
+        aweb::scope("/engage/status_updates/{status_update_id}/submissions")
+            .wrap(Authorization::new(grader_authorization))
+            .service(grading_page)
+            .service(submit_grade),
+    );
+
+    config
+        .service(submission_page)
+        .service(submit_status_update);
+}
+
+/// Create an empty status update creation form.
+async fn create_form() -> Result<Template, TelescopeError> {
+    let mut form = Template::new(CREATE_TEMPLATE);
+    form.fields = json!({
+        "semesters": CurrentSemesters::get().await?.semesters,
+    });
+    return Ok(form);
+}
+
+/// Status update creation form.
+#[get("")]
+async fn create_page(req: HttpRequest) -> Result<Page, TelescopeError> {
+    create_form()
+        .await?
+        .in_page(&req, "Create Status Update")
+        .await
+}
+
+/// Form submitted by coordinators to create a status update window.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct CreateForm {
+    /// The semester this status update is for.
+    semester: String,
+
+    /// The optional title. Default empty.
+    #[serde(default)]
+    title: String,
+
+    open_date: NaiveDate,
+
+    /// Cannot be a [`chrono::NaiveTime`], since seconds are not included.
+    open_time: String,
+
+    close_date: NaiveDate,
+
+    /// Cannot be a [`chrono::NaiveTime`], since seconds are not included.
+    close_time: String,
+}
+
+/// Endpoint to create a status update window.
+#[post("")]
+async fn submit_create(
+    req: HttpRequest,
+    Form(form): Form<CreateForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    // Create a form to send back if there are any issues.
+    let mut return_form: Template = create_form().await?;
+    return_form["selections"] = json!(&form);
+
+    // Convert the open and close times.
+    let open: Option<DateTime<Utc>> = local_timestamp(form.open_date, &form.open_time);
+    let close: Option<DateTime<Utc>> = local_timestamp(form.close_date, &form.close_time);
+
+    match (open, close) {
+        (None, _) => {
+            return_form["issues"]["open_time"] = json!("Could not parse open time.");
+        }
+        (_, None) => {
+            return_form["issues"]["close_time"] = json!("Could not parse close time.");
+        }
+        (Some(open), Some(close)) if close <= open => {
+            return_form["issues"]["close_time"] = json!("Close time must be after open time.");
+        }
+        _ => {}
+    }
+
+    // Make sure the semester is one of the current ones.
+    let semester_available: bool = return_form["semesters"]
+        .as_array()
+        .map(|semesters| {
+            semesters
+                .iter()
+                .any(|s| s["semester_id"] == form.semester.as_str())
+        })
+        .unwrap_or(false);
+    if !semester_available {
+        return_form["issues"]["semester"] = json!("Please select a current semester.");
+    }
+
+    // Return the form if there are any issues.
+    if !return_form["issues"].is_null() {
+        let page = return_form.in_page(&req, "Create Status Update").await?;
+        return Err(TelescopeError::InvalidForm(page));
+    }
+
+    // Empty titles are stored as null.
+    let title: Option<String> =
+        (!form.title.trim().is_empty()).then(|| form.title.trim().to_string());
+
+    CreateStatusUpdate::execute(form.semester, title, open.unwrap(), close.unwrap())
+        .await?
+        .ok_or(TelescopeError::ise(
+            "Status update creation did not return ID.",
+        ))?;
+
+    // Send the coordinator back to the engagement panel.
+    return Ok(HttpResponse::Found().header(LOCATION, "/engage").finish());
+}
+
+/// Get a status update with the user's submission or return a resource not found error.
+async fn get_status_update(
+    status_update_id: i64,
+    user_id: Uuid,
+) -> Result<StatusUpdateStatusUpdate, TelescopeError> {
+    StatusUpdate::get(status_update_id, user_id)
+        .await?
+        .ok_or(TelescopeError::resource_not_found(
+            "Status Update Not Found",
+            "Could not find a status update for this ID.",
+        ))
+}
+
+/// Create the submission form for a status update.
+fn submit_form(status_update: &StatusUpdateStatusUpdate) -> Template {
+    let mut form = Template::new(SUBMIT_TEMPLATE);
+    form.fields = json!({
+        "status_update": status_update,
+        "submission": status_update.submission.first(),
+        "is_open": status_update.is_open(),
+        "is_enrolled": !status_update.semester.enrollments.is_empty(),
+    });
+    return form;
+}
+
+/// Page to view and submit a status update.
+#[get("/engage/status_updates/{status_update_id}")]
+async fn submission_page(
+    req: HttpRequest,
+    auth: AuthenticationCookie,
+    Path(status_update_id): Path<i64>,
+) -> Result<Page, TelescopeError> {
+    let user_id: Uuid = auth.get_user_id_or_error().await?;
+    let status_update = get_status_update(status_update_id, user_id).await?;
+    return submit_form(&status_update)
+        .in_page(&req, "Status Update")
+        .await;
+}
+
+/// Form submitted by students with their status update.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct SubmissionForm {
+    this_week: String,
+    next_week: String,
+    blockers: String,
+}
+
+/// Endpoint to submit a status update.
+#[post("/engage/status_updates/{status_update_id}")]
+async fn submit_status_update(
+    req: HttpRequest,
+    auth: AuthenticationCookie,
+    Path(status_update_id): Path<i64>,
+    Form(form): Form<SubmissionForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    let user_id: Uuid = auth.get_user_id_or_error().await?;
+    let status_update = get_status_update(status_update_id, user_id).await?;
+
+    // Make a form to send back if anything is wrong.
+    let mut return_form: Template = submit_form(&status_update);
+    return_form["submission"] = json!(&form);
+
+    if status_update.semester.enrollments.is_empty() {
+        return_form["issue"] = json!("You are not enrolled in this semester.");
+    } else if !status_update.is_open() {
+        return_form["issue"] = json!("This status update is not accepting submissions.");
+    } else if status_update
+        .submission
+        .first()
+        .map(|submission| submission.grade.is_some())
+        .unwrap_or(false)
+    {
+        return_form["issue"] = json!("Your submission has already been graded.");
+    } else if form.this_week.trim().is_empty() || form.next_week.trim().is_empty() {
+        return_form["issue"] =
+            json!("Please fill out what you did this week and what you plan to do next week.");
+    }
+
+    if !return_form["issue"].is_null() {
+        let page = return_form.in_page(&req, "Status Update").await?;
+        return Err(TelescopeError::InvalidForm(page));
+    }
+
+    SubmitStatusUpdate::execute(
+        status_update_id,
+        user_id,
+        form.this_week.trim().to_string(),
+        form.next_week.trim().to_string(),
+        form.blockers.trim().to_string(),
+    )
+    .await?;
+
+    // Send the student back to the engagement panel.
+    return Ok(HttpResponse::Found().header(LOCATION, "/engage").finish());
+}
+
+/// Page to grade the submissions to a status update.
+#[get("")]
+async fn grading_page(
+    req: HttpRequest,
+    Path(status_update_id): Path<i64>,
+) -> Result<Page, TelescopeError> {
+    let status_update = StatusUpdateSubmissions::get(status_update_id)
+        .await?
+        .ok_or(TelescopeError::resource_not_found(
+            "Status Update Not Found",
+            "Could not find a status update for this ID.",
+        ))?;
+
+    let mut template = Template::new(GRADING_TEMPLATE);
+    template.fields = json!({
+        "status_update": status_update,
+    });
+    return template.in_page(&req, "Grade Status Updates").await;
+}
+
+/// Form submitted by graders to grade a submission.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct GradeForm {
+    /// Grade on a scale from 0 to 1.
+    grade: f64,
+
+    #[serde(default)]
+    grader_comments: String,
+}
+
+/// Endpoint to grade a submission.
+#[post("/{user_id}/grade")]
+async fn submit_grade(
+    auth: AuthenticationCookie,
+    Path((status_update_id, user_id)): Path<(i64, Uuid)>,
+    Form(form): Form<GradeForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    let grader_id: Uuid = auth.get_user_id_or_error().await?;
+
+    // Graders cannot grade their own submissions.
+    if grader_id == user_id {
+        return Err(TelescopeError::BadRequest {
+            header: "Cannot Grade Own Submission".into(),
+            message: "You cannot grade your own status update.".into(),
+            show_status_code: false,
+        });
+    }
+
+    // Check the grade range.
+    if !(0.0..=1.0).contains(&form.grade) {
+        return Err(TelescopeError::BadRequest {
+            header: "Invalid Grade".into(),
+            message: "Grades must be between 0 and 1.".into(),
+            show_status_code: false,
+        });
+    }
+
+    let comments: Option<String> =
+        (!form.grader_comments.trim().is_empty()).then(|| form.grader_comments.trim().to_string());
+
+    GradeStatusUpdate::execute(status_update_id, user_id, grader_id, form.grade, comments)
+        .await?
+        .ok_or(TelescopeError::resource_not_found(
+            "Submission Not Found",
+            "Could not find a submission by this user for this status update.",
+        ))?;
+
+    // Send the grader back to the grading page.
+    return Ok(HttpResponse::Found()
+        .header(
+            LOCATION,
+            format!("/engage/status_updates/{}/submissions", status_update_id),
+        )
+        .finish());
+}
@@ -1,5 +1,6 @@
 //! Module for serving the RCOS homepage.
 
+use crate::api::rcos::announcements::ActiveAnnouncements;
 use crate::api::rcos::landing_page_stats::LandingPageStatistics;
 use crate::error::TelescopeError;
 use crate::templates::page::Page;
@@ -17,5 +18,11 @@ pub async fn index(req: HttpRequest) -> Result<Page, TelescopeError> {
     // Make and return a template with the statistics.
     let mut template = Template::new(TEMPLATE_PATH);
     template["stats"] = json!(stats);
+    // Show the full text of active announcements. The homepage still loads without them.
+    let announcements = ActiveAnnouncements::get().await.unwrap_or_else(|err| {
+        error!("Could not get active announcements: {}", err);
+        Vec::new()
+    });
+    template["announcements"] = json!(announcements);
     return template.in_page(&req, "RCOS").await;
 }
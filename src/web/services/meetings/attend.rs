@@ -0,0 +1,185 @@
+//! Meeting attendance check-in services.
+//!
+//! Students check into a meeting by submitting the meeting's attendance code while the meeting
+//! is going on. Hosts (and coordinators) can generate or rotate the attendance code from the
+//! meeting page.
+
+use crate::api::rcos::meetings::attendance::attendance_context::AttendanceContextMeeting;
+use crate::api::rcos::meetings::attendance::{AttendanceContext, Attend, SetAttendanceCode};
+use crate::api::rcos::meetings::authorization_for::{AuthorizationFor, UserMeetingAuthorization};
+use crate::error::TelescopeError;
+use crate::templates::page::Page;
+use crate::templates::Template;
+use crate::web::services::auth::identity::AuthenticationCookie;
+use actix_web::http::header::LOCATION;
+use actix_web::web::{Form, Path, ServiceConfig};
+use actix_web::{HttpRequest, HttpResponse};
+use chrono::Utc;
+
+/// The path from the templates directory to the check-in form.
+const TEMPLATE_PATH: &'static str = "meetings/attend";
+
+/// Register meeting attendance services.
+pub fn register(config: &mut ServiceConfig) {
+    config
+        .service(attend_page)
+        .service(submit_attendance)
+        .service(rotate_code);
+}
+
+/// Form submitted by users checking into a meeting.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct AttendForm {
+    /// The attendance code displayed by the meeting host.
+    code: String,
+}
+
+/// Get the attendance context for a meeting or return a resource not found error.
+async fn get_context(
+    meeting_id: i64,
+    user_id: uuid::Uuid,
+) -> Result<AttendanceContextMeeting, TelescopeError> {
+    AttendanceContext::get(meeting_id, user_id)
+        .await?
+        .ok_or(TelescopeError::resource_not_found(
+            "Meeting Not Found",
+            "Could not find a meeting for this ID.",
+        ))
+}
+
+/// Check whether the user can currently check into a meeting. Return a description of the
+/// problem if they cannot.
+fn check_in_issue(meeting: &AttendanceContextMeeting) -> Option<&'static str> {
+    let now = Utc::now();
+    if meeting.is_draft {
+        return Some("This meeting is a draft and is not taking attendance.");
+    }
+    if meeting.semester.enrollments.is_empty() {
+        return Some("You are not enrolled in the semester this meeting is part of.");
+    }
+    if now < meeting.start_date_time {
+        return Some("This meeting has not started yet.");
+    }
+    if now > meeting.end_date_time {
+        return Some("This meeting has already ended.");
+    }
+    if meeting.attendance_code.is_none() {
+        return Some("The host has not set an attendance code for this meeting yet.");
+    }
+    return None;
+}
+
+/// Create the check-in form for a meeting.
+fn make_form(meeting: &AttendanceContextMeeting) -> Template {
+    let mut form = Template::new(TEMPLATE_PATH);
+    form.fields = json!({
+        "meeting_id": meeting.meeting_id,
+        "title": meeting.title,
+        "type": meeting.type_,
+        "start_date_time": meeting.start_date_time,
+        "end_date_time": meeting.end_date_time,
+        "semester": {
+            "semester_id": meeting.semester.semester_id,
+            "title": meeting.semester.title,
+        },
+        // Never send the attendance code itself to the template.
+        "attendance": meeting.meeting_attendances.first(),
+        "issue": check_in_issue(meeting),
+    });
+    return form;
+}
+
+/// Page to check into a meeting.
+#[get("/meetings/{meeting_id}/attend")]
+async fn attend_page(
+    req: HttpRequest,
+    auth: AuthenticationCookie,
+    Path(meeting_id): Path<i64>,
+) -> Result<Page, TelescopeError> {
+    // Get the viewer's user ID and the meeting context.
+    let user_id = auth.get_user_id_or_error().await?;
+    let meeting = get_context(meeting_id, user_id).await?;
+
+    // Render the check-in form.
+    return make_form(&meeting).in_page(&req, "Meeting Check-In").await;
+}
+
+/// Endpoint to submit an attendance code.
+#[post("/meetings/{meeting_id}/attend")]
+async fn submit_attendance(
+    req: HttpRequest,
+    auth: AuthenticationCookie,
+    Path(meeting_id): Path<i64>,
+    Form(form): Form<AttendForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    // Get the viewer's user ID and the meeting context.
+    let user_id = auth.get_user_id_or_error().await?;
+    let meeting = get_context(meeting_id, user_id).await?;
+
+    // If the user is already marked present, just send them back to the check-in page.
+    if !meeting.meeting_attendances.is_empty() {
+        return Ok(HttpResponse::Found()
+            .header(LOCATION, format!("/meetings/{}/attend", meeting_id))
+            .finish());
+    }
+
+    // Make a form to send back if anything is wrong.
+    let mut return_form: Template = make_form(&meeting);
+
+    // The form already explains any problems with the meeting or enrollment.
+    if return_form["issue"].is_null() {
+        // Compare the submitted code with the meeting's code. This is case-insensitive, since
+        // codes are read off of a screen.
+        let code_matches: bool = meeting
+            .attendance_code
+            .as_ref()
+            .map(|code| code.trim().eq_ignore_ascii_case(form.code.trim()))
+            .unwrap_or(false);
+
+        if !code_matches {
+            return_form["issue"] = json!("Incorrect attendance code.");
+        }
+    }
+
+    // Return the form if there are any issues.
+    if !return_form["issue"].is_null() {
+        let page = return_form.in_page(&req, "Meeting Check-In").await?;
+        return Err(TelescopeError::InvalidForm(page));
+    }
+
+    // Record the attendance.
+    Attend::execute(meeting_id, user_id).await?;
+
+    // Redirect the user back to the check-in page, which will confirm their attendance.
+    return Ok(HttpResponse::Found()
+        .header(LOCATION, format!("/meetings/{}/attend", meeting_id))
+        .finish());
+}
+
+/// Endpoint for meeting hosts to generate or rotate the attendance code of a meeting.
+/// Uses post to prevent inadvertent rotation.
+#[post("/meetings/{meeting_id}/attendance_code")]
+async fn rotate_code(
+    auth: AuthenticationCookie,
+    Path(meeting_id): Path<i64>,
+) -> Result<HttpResponse, TelescopeError> {
+    // Require that the authenticated user can edit this meeting.
+    let user_id = auth.get_user_id_or_error().await?;
+    let authorization: UserMeetingAuthorization = AuthorizationFor::get(Some(user_id)).await?;
+    if !authorization.can_edit_by_id(meeting_id).await? {
+        return Err(TelescopeError::Forbidden);
+    }
+
+    // Generate and store the new code.
+    SetAttendanceCode::rotate(meeting_id)
+        .await?
+        .ok_or(TelescopeError::resource_not_found(
+            "Meeting Not Found",
+            "Could not find a meeting for this ID.",
+        ))?;
+
+    // Send the host back to the meeting page, where the new code is displayed.
+    return Ok(HttpResponse::Found()
+        .header(LOCATION, format!("/meeting/{}", meeting_id))
+        .finish());
+}
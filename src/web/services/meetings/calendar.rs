@@ -0,0 +1,219 @@
+//! iCalendar (RFC 5545) feeds of RCOS meetings, for subscribing to meetings from calendar
+//! apps like Google Calendar.
+//!
+//! Calendar apps cannot log in, so feeds that include non-public meetings are authenticated
+//! by a secret per-user token in the URL.
+
+use crate::api::rcos::meetings::authorization_for::{AuthorizationFor, UserMeetingAuthorization};
+use crate::api::rcos::meetings::calendar::{CalendarTokenOwner, ResetCalendarToken};
+use crate::api::rcos::meetings::get::{meetings::MeetingsMeetings, Meetings};
+use crate::api::rcos::meetings::MeetingType;
+use crate::env::global_config;
+use crate::error::TelescopeError;
+use crate::web::services::auth::identity::AuthenticationCookie;
+use actix_web::http::header::LOCATION;
+use actix_web::web::{Query, ServiceConfig};
+use actix_web::HttpResponse;
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+/// Register calendar feed services.
+pub fn register(config: &mut ServiceConfig) {
+    config.service(feed).service(reset_token);
+}
+
+/// Get the URL of a calendar feed, optionally authenticated by a user's token.
+pub fn feed_url(token: Option<Uuid>) -> String {
+    match token {
+        Some(token) => format!(
+            "{}/meetings.ics?token={}",
+            global_config().telescope_url,
+            token
+        ),
+        None => format!("{}/meetings.ics", global_config().telescope_url),
+    }
+}
+
+/// Query parameters for calendar feeds.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct FeedQuery {
+    /// The secret calendar token of the user this feed is for. Without one, the feed only
+    /// has public meetings.
+    token: Option<Uuid>,
+
+    /// Only include meetings of this type.
+    #[serde(rename = "type")]
+    meeting_type: Option<MeetingType>,
+}
+
+/// Calendar feed of RCOS meetings from four weeks ago to six months from now.
+#[get("/meetings.ics")]
+async fn feed(Query(query): Query<FeedQuery>) -> Result<HttpResponse, TelescopeError> {
+    // Resolve the user this feed is for.
+    let user_id: Option<Uuid> = match query.token {
+        Some(token) => Some(CalendarTokenOwner::get(token).await?.ok_or(
+            TelescopeError::resource_not_found(
+                "Calendar Not Found",
+                "This calendar link is not valid. It may have been reset.",
+            ),
+        )?),
+        None => None,
+    };
+
+    // Only include the meeting types this user can see.
+    let authorization: UserMeetingAuthorization = AuthorizationFor::get(user_id).await?;
+    let types: Vec<MeetingType> = authorization
+        .viewable_types()
+        .into_iter()
+        .filter(|ty| {
+            query
+                .meeting_type
+                .map(|filter| filter == *ty)
+                .unwrap_or(true)
+        })
+        .collect();
+
+    let now: DateTime<Utc> = Utc::now();
+    // Drafts are left out since calendar apps have no way to show they are not final.
+    let meetings: Vec<MeetingsMeetings> = Meetings::get(
+        now - Duration::weeks(4),
+        now + Duration::weeks(26),
+        false,
+        types,
+    )
+    .await?;
+
+    return Ok(HttpResponse::Ok()
+        .content_type("text/calendar; charset=utf-8")
+        .body(make_calendar(&meetings, now)));
+}
+
+/// Endpoint to replace the authenticated user's calendar token. Feeds using the old token stop
+/// working.
+#[post("/meetings/calendar/reset")]
+async fn reset_token(auth: AuthenticationCookie) -> Result<HttpResponse, TelescopeError> {
+    let user_id: Uuid = auth.get_user_id_or_error().await?;
+    ResetCalendarToken::execute(user_id).await?;
+    return Ok(HttpResponse::Found().header(LOCATION, "/meetings").finish());
+}
+
+/// Escape a text value as described in RFC 5545 section 3.3.11.
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// Format a timestamp as an RFC 5545 UTC date-time.
+fn format_timestamp(timestamp: &DateTime<Utc>) -> String {
+    timestamp.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Add a content line to a calendar, folding it to lines of at most 75 octets as described in
+/// RFC 5545 section 3.1.
+fn push_line(calendar: &mut String, line: &str) {
+    let mut line_length: usize = 0;
+    for c in line.chars() {
+        // Continuation lines start with a space, which counts toward their length.
+        if line_length + c.len_utf8() > 75 {
+            calendar.push_str("\r\n ");
+            line_length = 1;
+        }
+        calendar.push(c);
+        line_length += c.len_utf8();
+    }
+    calendar.push_str("\r\n");
+}
+
+/// Build an iCalendar document with an event for each meeting.
+fn make_calendar(meetings: &[MeetingsMeetings], now: DateTime<Utc>) -> String {
+    let telescope_url: String = global_config().telescope_url.clone();
+    // Use the host of the Telescope URL in event UIDs so they are globally unique.
+    let uid_domain: String = url::Url::parse(telescope_url.as_str())
+        .ok()
+        .and_then(|url| url.host_str().map(String::from))
+        .unwrap_or("telescope".into());
+
+    let mut calendar = String::new();
+    push_line(&mut calendar, "BEGIN:VCALENDAR");
+    push_line(&mut calendar, "VERSION:2.0");
+    push_line(&mut calendar, "PRODID:-//RCOS//Telescope//EN");
+    push_line(&mut calendar, "CALSCALE:GREGORIAN");
+    push_line(&mut calendar, "X-WR-CALNAME:RCOS Meetings");
+
+    for meeting in meetings {
+        let meeting_page = format!("{}/meeting/{}", telescope_url, meeting.meeting_id);
+        let title: String = meeting.title.clone().unwrap_or(format!(
+            "RCOS {} - {}",
+            meeting.type_,
+            meeting.start_date_time.format("%B %_d, %Y")
+        ));
+
+        // The description has the host and links, followed by the agenda.
+        let mut description: Vec<String> = Vec::new();
+        if let Some(host) = meeting.host.as_ref() {
+            description.push(format!("Host: {} {}", host.first_name, host.last_name));
+        }
+        if let Some(meeting_url) = meeting.meeting_url.as_ref() {
+            description.push(format!("Join: {}", meeting_url));
+        }
+        if let Some(slides) = meeting.external_presentation_url.as_ref() {
+            description.push(format!("Slides: {}", slides));
+        }
+        description.push(format!("Details: {}", meeting_page));
+        if !meeting.description.trim().is_empty() {
+            description.push(String::new());
+            description.push(meeting.description.trim().to_string());
+        }
+
+        // Remote meetings without a location are at their meeting URL.
+        let location: Option<&String> = meeting
+            .location
+            .as_ref()
+            .filter(|location| !location.trim().is_empty())
+            .or(meeting.meeting_url.as_ref());
+
+        push_line(&mut calendar, "BEGIN:VEVENT");
+        push_line(
+            &mut calendar,
+            format!("UID:meeting-{}@{}", meeting.meeting_id, uid_domain).as_str(),
+        );
+        push_line(
+            &mut calendar,
+            format!("DTSTAMP:{}", format_timestamp(&now)).as_str(),
+        );
+        push_line(
+            &mut calendar,
+            format!("DTSTART:{}", format_timestamp(&meeting.start_date_time)).as_str(),
+        );
+        push_line(
+            &mut calendar,
+            format!("DTEND:{}", format_timestamp(&meeting.end_date_time)).as_str(),
+        );
+        push_line(
+            &mut calendar,
+            format!("SUMMARY:{}", escape_text(&title)).as_str(),
+        );
+        if let Some(location) = location {
+            push_line(
+                &mut calendar,
+                format!("LOCATION:{}", escape_text(location)).as_str(),
+            );
+        }
+        push_line(
+            &mut calendar,
+            format!("DESCRIPTION:{}", escape_text(&description.join("\n"))).as_str(),
+        );
+        push_line(
+            &mut calendar,
+            format!("CATEGORIES:{}", escape_text(&meeting.type_.to_string())).as_str(),
+        );
+        push_line(&mut calendar, format!("URL:{}", meeting_page).as_str());
+        push_line(&mut calendar, "END:VEVENT");
+    }
+
+    push_line(&mut calendar, "END:VCALENDAR");
+    return calendar;
+}
@@ -6,8 +6,10 @@
 //! to finish meeting creation.
 
 use crate::api::rcos::meetings::authorization_for::UserMeetingAuthorization;
+use crate::api::rcos::meetings::conflicts::{MeetingConflicts, ScheduledMeeting};
 use crate::api::rcos::meetings::creation::context::CreationContext;
-use crate::api::rcos::meetings::creation::create::CreateMeeting;
+use crate::api::rcos::meetings::creation::create::{create_meeting, CreateMeeting};
+use crate::api::rcos::meetings::creation::create_series::CreateMeetingSeries;
 use crate::api::rcos::meetings::creation::host_selection::HostSelection;
 use crate::api::rcos::meetings::{MeetingType, ALL_MEETING_TYPES};
 use crate::error::TelescopeError;
@@ -19,7 +21,7 @@ use actix_web::web as aweb;
 use actix_web::web::{Form, Query, ServiceConfig};
 use actix_web::HttpRequest;
 use actix_web::HttpResponse;
-use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
 use serde_json::Value;
 use uuid::Uuid;
 
@@ -153,8 +155,55 @@ pub struct FinishForm {
     #[serde(default)]
     pub external_slides_url: Option<String>,
 
+    /// Markdown for the meeting's RevealJS slides. Only used when editing meetings.
+    #[serde(default)]
+    pub presentation_markdown: String,
+
     #[serde(default)]
     pub is_draft: Option<bool>,
+
+    /// How the meeting repeats, either "weekly" or "biweekly". Empty for a single meeting.
+    /// Only used when creating meetings.
+    #[serde(default)]
+    pub recurrence: String,
+
+    /// The last date a recurring meeting can be on. Empty for the end of the semester.
+    #[serde(default)]
+    pub recurrence_end: String,
+
+    /// Comma separated dates to skip in a recurring series, such as holidays.
+    #[serde(default)]
+    pub skip_dates: String,
+
+    /// Either "this" to edit just one meeting or "future" to also edit the later meetings in
+    /// its series. Only used when editing meetings.
+    #[serde(default)]
+    pub apply_to: String,
+
+    /// Save the meeting even if it conflicts with other meetings. Checkboxes are only sent
+    /// when checked.
+    #[serde(default)]
+    pub ignore_conflicts: Option<bool>,
+}
+
+/// Get the number of days between the meetings of a recurring series. Return `None` for
+/// single meetings.
+fn recurrence_interval(recurrence: &str) -> Option<i64> {
+    match recurrence {
+        "weekly" => Some(7),
+        "biweekly" => Some(14),
+        _ => None,
+    }
+}
+
+/// Parse a list of dates separated by commas or whitespace. Return the first entry that is
+/// not a valid date on failure.
+fn parse_dates(dates: &str) -> Result<Vec<NaiveDate>, String> {
+    dates
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|date| !date.is_empty())
+        .map(|date| date.parse::<NaiveDate>().map_err(|_| date.to_string()))
+        .collect()
 }
 
 /// Endpoint that users submit meeting creation forms to.
@@ -189,6 +238,11 @@ async fn submit_meeting(
         recording_url,
         external_slides_url,
         is_draft,
+        recurrence,
+        recurrence_end,
+        skip_dates,
+        ignore_conflicts,
+        ..
     } = form;
 
     // We assume that semester_id is valid, since it includes only options from the creation
@@ -247,6 +301,39 @@ async fn submit_meeting(
         return_form["issues"]["end_date"] = json!("End date is before start date.");
     }
 
+    // Check the recurrence options. Recurring meetings repeat until the end date, or the end
+    // of the semester if there is none.
+    let interval: Option<i64> = recurrence_interval(recurrence.as_str());
+    let mut last_date: NaiveDate = start_date;
+    let mut skipped_dates: Vec<NaiveDate> = Vec::new();
+    if interval.is_some() {
+        last_date = semester_end;
+        if !recurrence_end.trim().is_empty() {
+            match recurrence_end.trim().parse::<NaiveDate>() {
+                Ok(date) if date > semester_end => {
+                    return_form["issues"]["recurrence_end"] =
+                        json!("Recurrence end date is after the semester ends.");
+                }
+                Ok(date) if date < start_date => {
+                    return_form["issues"]["recurrence_end"] =
+                        json!("Recurrence end date is before start date.");
+                }
+                Ok(date) => last_date = date,
+                Err(_) => {
+                    return_form["issues"]["recurrence_end"] = json!("Please enter a valid date.");
+                }
+            }
+        }
+
+        match parse_dates(skip_dates.as_str()) {
+            Ok(dates) => skipped_dates = dates,
+            Err(invalid) => {
+                return_form["issues"]["skip_dates"] =
+                    json!(format!("\"{}\" is not a valid date (YYYY-MM-DD).", invalid));
+            }
+        }
+    }
+
     // Check for errors and return form if necessary.
     if return_form["issues"] != json!(null) {
         let page = return_form.in_page(&req, "Create Meeting").await?;
@@ -281,50 +368,125 @@ async fn submit_meeting(
         return Err(TelescopeError::InvalidForm(page));
     }
 
+    // Get the start and end of every meeting in the series. This is just the one meeting if
+    // it does not repeat. Recurring meetings keep the same local time across daylight saving
+    // time changes.
+    let mut occurrences: Vec<(NaiveDateTime, NaiveDateTime)> = Vec::new();
+    let mut offset: Duration = Duration::zero();
+    while start_date + offset <= last_date {
+        if !skipped_dates.contains(&(start_date + offset)) {
+            occurrences.push((start + offset, end + offset));
+        }
+
+        match interval {
+            Some(days) => offset = offset + Duration::days(days),
+            None => break,
+        }
+    }
+
+    if occurrences.is_empty() {
+        return_form["issues"]["skip_dates"] = json!("Every meeting in the series is skipped.");
+        let page = return_form.in_page(&req, "Create Meeting").await?;
+        return Err(TelescopeError::InvalidForm(page));
+    }
+
     // Ascribe local timezone.
-    let start: DateTime<Local> = Local
-        .from_local_datetime(&start)
-        // Expect that there is only one valid local time for this.
-        .single()
-        .ok_or(TelescopeError::BadRequest {
-            header: "Malformed Meeting Creation Form".into(),
-            message: "Could not ascribe local timezone to start timestamp.".into(),
-            show_status_code: false,
-        })?;
+    let mut times: Vec<(DateTime<Utc>, DateTime<Utc>)> = Vec::with_capacity(occurrences.len());
+    for (start, end) in occurrences {
+        let start: DateTime<Local> = Local
+            .from_local_datetime(&start)
+            // Expect that there is only one valid local time for this.
+            .single()
+            .ok_or(TelescopeError::BadRequest {
+                header: "Malformed Meeting Creation Form".into(),
+                message: "Could not ascribe local timezone to start timestamp.".into(),
+                show_status_code: false,
+            })?;
+
+        let end: DateTime<Local> = Local
+            .from_local_datetime(&end)
+            // Expect that there is only one valid local time for this.
+            .single()
+            .ok_or(TelescopeError::BadRequest {
+                header: "Malformed Meeting Creation Form".into(),
+                message: "Could not ascribe local timezone to end timestamp.".into(),
+                show_status_code: false,
+            })?;
+
+        times.push((start.with_timezone(&Utc), end.with_timezone(&Utc)));
+    }
 
-    let end: DateTime<Local> = Local
-        .from_local_datetime(&end)
-        // Expect that there is only one valid local time for this.
-        .single()
-        .ok_or(TelescopeError::BadRequest {
-            header: "Malformed Meeting Creation Form".into(),
-            message: "Could not ascribe local timezone to end timestamp.".into(),
-            show_status_code: false,
-        })?;
+    let location: Option<String> =
+        location.and_then(|string| (!string.trim().is_empty()).then(|| string.trim().to_string()));
+
+    // Warn the user about double-booked hosts, rooms, and small groups unless they already
+    // chose to ignore the conflicts.
+    if !ignore_conflicts.unwrap_or(false) {
+        let conflicts = MeetingConflicts::get(&ScheduledMeeting {
+            semester_id: semester.clone(),
+            kind,
+            host,
+            location: location.clone(),
+            times: times.clone(),
+            exclude: Vec::new(),
+        })
+        .await?;
+
+        if !conflicts.is_empty() {
+            return_form["conflicts"] = json!(conflicts);
+            let page = return_form.in_page(&req, "Create Meeting").await?;
+            return Err(TelescopeError::InvalidForm(page));
+        }
+    }
 
     // The rest of the fields are managed pretty tersely in the API call and do not need validation
     // or feedback.
-    let created_meeting_id: i64 = CreateMeeting::execute(
+    let meeting = create_meeting::Variables {
         host,
         title,
-        start.with_timezone(&Utc),
-        end.with_timezone(&Utc),
-        description.trim().to_string(),
-        is_draft.unwrap_or(false),
-        is_remote.unwrap_or(false),
-        location.and_then(|string| (!string.trim().is_empty()).then(|| string.trim().to_string())),
+        start: times[0].0,
+        end: times[0].1,
+        description: description.trim().to_string(),
+        is_draft: is_draft.unwrap_or(false),
+        is_remote: is_remote.unwrap_or(false),
+        location,
         meeting_url,
         recording_url,
         external_slides_url,
-        semester,
+        semester_id: semester,
         kind,
-    )
-    .await?
+    };
+
+    let created_meeting_id: i64 = if interval.is_some() {
+        // Create the whole series at once.
+        CreateMeetingSeries::execute(meeting, times)
+            .await?
+            .first()
+            .copied()
+    } else {
+        CreateMeeting::execute(
+            meeting.host,
+            meeting.title,
+            meeting.start,
+            meeting.end,
+            meeting.description,
+            meeting.is_draft,
+            meeting.is_remote,
+            meeting.location,
+            meeting.meeting_url,
+            meeting.recording_url,
+            meeting.external_slides_url,
+            meeting.semester_id,
+            meeting.kind,
+        )
+        .await?
+    }
     .ok_or(TelescopeError::ise(
         "Meeting creation call did not return ID.",
     ))?;
 
-    // Redirect the user to the page for the meeting they created.
+    // Redirect the user to the page for the meeting they created (or the first meeting of the
+    // series they created).
     return Ok(HttpResponse::Found()
         .header(LOCATION, format!("/meeting/{}", created_meeting_id))
         .finish());
@@ -1,5 +1,6 @@
 //! Services to support meeting edits.
 
+use crate::api::rcos::meetings::conflicts::{MeetingConflicts, ScheduledMeeting};
 use crate::api::rcos::meetings::creation::create::normalize_url;
 use crate::api::rcos::meetings::edit::EditHostSelection;
 use crate::api::rcos::meetings::ALL_MEETING_TYPES;
@@ -20,7 +21,7 @@ use actix_web::{
     web::{Path, Query, ServiceConfig},
     HttpRequest, HttpResponse,
 };
-use chrono::{DateTime, Local, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use chrono::{DateTime, Duration, Local, NaiveDateTime, NaiveTime, TimeZone, Utc};
 use serde_json::Value;
 use uuid::Uuid;
 
@@ -199,6 +200,7 @@ async fn submit_meeting_edits(
         end_date,
         description,
         external_slides_url,
+        presentation_markdown,
         is_remote,
         is_draft,
         semester,
@@ -207,6 +209,9 @@ async fn submit_meeting_edits(
         location,
         kind,
         title,
+        apply_to,
+        ignore_conflicts,
+        ..
     } = form_data;
 
     // Like the creation system, semester ID, meeting kind, and host ID are not validated.
@@ -239,6 +244,11 @@ async fn submit_meeting_edits(
     form["data"]["recording_url"] = json!(&recording_url);
     form["data"]["external_presentation_url"] = json!(&external_slides_url);
 
+    // Slides are optional. Keep whitespace, since it is significant in markdown.
+    let presentation_markdown: Option<String> =
+        (!presentation_markdown.trim().is_empty()).then(|| presentation_markdown);
+    form["data"]["presentation_markdown"] = json!(&presentation_markdown);
+
     // Handle flags.
     let is_remote: bool = is_remote.unwrap_or(false);
     let is_draft: bool = is_draft.unwrap_or(false);
@@ -324,12 +334,76 @@ async fn submit_meeting_edits(
         show_status_code: false,
     })?;
 
+    // Extract the host from context object.
+    let host: Option<Uuid> = form["context"]["host"][0]["id"]
+        .as_str()
+        .and_then(|host_id| host_id.parse::<Uuid>().ok());
+
+    let start: DateTime<Utc> = start.with_timezone(&Utc);
+    let end: DateTime<Utc> = end.with_timezone(&Utc);
+
+    // Get the later meetings in the series if the edit applies to them. They are moved by as
+    // much as this one moved.
+    let later_meetings: Option<Vec<(i64, DateTime<Utc>, DateTime<Utc>)>> =
+        if let (Some(series_id), "future") = (meeting_data.series_id, apply_to.as_str()) {
+            let start_shift: Duration = start - meeting_data.start_date_time;
+            let end_shift: Duration = end - meeting_data.end_date_time;
+            let later_meetings =
+                edit::SeriesMeetings::get(series_id, meeting_data.start_date_time, meeting_id)
+                    .await?
+                    .iter()
+                    .map(|meeting| {
+                        (
+                            meeting.meeting_id,
+                            meeting.start_date_time + start_shift,
+                            meeting.end_date_time + end_shift,
+                        )
+                    })
+                    .collect();
+            Some(later_meetings)
+        } else {
+            None
+        };
+
+    // Warn the user about double-booked hosts, rooms, and small groups unless they already
+    // chose to ignore the conflicts. Every edited meeting is checked at its new time, and none
+    // of them conflict with their own old times.
+    if !ignore_conflicts.unwrap_or(false) {
+        let mut times: Vec<(DateTime<Utc>, DateTime<Utc>)> = vec![(start, end)];
+        let mut exclude: Vec<i64> = vec![meeting_id];
+        for (later_id, later_start, later_end) in later_meetings.iter().flatten() {
+            times.push((*later_start, *later_end));
+            exclude.push(*later_id);
+        }
+
+        let conflicts = MeetingConflicts::get(&ScheduledMeeting {
+            semester_id: semester.clone(),
+            kind,
+            host,
+            location: location.clone(),
+            times,
+            exclude,
+        })
+        .await?;
+
+        if !conflicts.is_empty() {
+            form["conflicts"] = json!(conflicts);
+            let page = form
+                .in_page(
+                    &req,
+                    format!("Edit {}", resolve_meeting_title(&meeting_data)),
+                )
+                .await?;
+            return Err(TelescopeError::InvalidForm(page));
+        }
+    }
+
     // Create variables for mutation.
     let edit_mutation_variables = edit::edit_meeting::Variables {
         meeting_id,
         title,
-        start: start.with_timezone(&Utc),
-        end: end.with_timezone(&Utc),
+        start,
+        end,
         semester_id: semester,
         kind,
         description,
@@ -338,17 +412,21 @@ async fn submit_meeting_edits(
         meeting_url: normalize_url(meeting_url),
         location,
         external_slides_url: normalize_url(external_slides_url),
+        presentation_markdown,
         recording_url: normalize_url(recording_url),
-        // Extract the host from context object.
-        host: form["context"]["host"][0]["id"]
-            .as_str()
-            .and_then(|host_id| host_id.parse::<Uuid>().ok()),
+        host,
+    };
+
+    // Edit the later meetings in the series in the same mutation if requested.
+    let edited: Option<i64> = match later_meetings {
+        Some(later_meetings) => {
+            edit::EditMeetingSeries::execute(edit_mutation_variables, later_meetings).await?
+        }
+        None => edit::EditMeeting::execute(edit_mutation_variables).await?,
     };
 
     // The returned meeting ID should match the existing one but we don't check.
-    let meeting_id: i64 = edit::EditMeeting::execute(edit_mutation_variables)
-        .await?
-        .unwrap_or(meeting_id);
+    let meeting_id: i64 = edited.unwrap_or(meeting_id);
 
     // Redirect the user back to the meeting they edited.
     return Ok(HttpResponse::Found()
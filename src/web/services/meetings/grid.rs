@@ -0,0 +1,200 @@
+//! Calendar grid views of the meetings page.
+//!
+//! Grids are built server-side in the viewer's timezone, so that meetings land on the right
+//! day and times read the same as on the viewer's clock.
+
+use crate::api::rcos::meetings::get::meetings::MeetingsMeetings;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use serde_json::Value;
+
+/// The timezone of users without a preference. RCOS meetings are held in New York.
+pub const DEFAULT_TIMEZONE: Tz = chrono_tz::America::New_York;
+
+/// The layout of a calendar grid.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum GridView {
+    /// One row per week of a month.
+    Month,
+    /// A single week.
+    Week,
+}
+
+/// The dates shown on a calendar grid and the neighboring grids.
+#[derive(Copy, Clone, Debug)]
+pub struct GridBounds {
+    /// The first date on the grid. This is always a Sunday.
+    pub start: NaiveDate,
+    /// The day after the last date on the grid.
+    pub end: NaiveDate,
+    /// A date on the previous grid.
+    pub previous: NaiveDate,
+    /// A date on the next grid.
+    pub next: NaiveDate,
+}
+
+/// Get the first day of the month after the one a date is in.
+fn next_month(date: NaiveDate) -> NaiveDate {
+    if date.month() == 12 {
+        NaiveDate::from_ymd(date.year() + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd(date.year(), date.month() + 1, 1)
+    }
+}
+
+/// Get the first day of the month before the one a date is in.
+fn previous_month(date: NaiveDate) -> NaiveDate {
+    if date.month() == 1 {
+        NaiveDate::from_ymd(date.year() - 1, 12, 1)
+    } else {
+        NaiveDate::from_ymd(date.year(), date.month() - 1, 1)
+    }
+}
+
+/// Get the Sunday on or before a date.
+fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_sunday() as i64)
+}
+
+impl GridView {
+    /// Get the dates of the grid containing a date.
+    pub fn bounds(self, date: NaiveDate) -> GridBounds {
+        match self {
+            GridView::Month => {
+                let first: NaiveDate = date.with_day(1).unwrap();
+                let last: NaiveDate = next_month(date).pred();
+                GridBounds {
+                    start: week_start(first),
+                    end: week_start(last) + Duration::weeks(1),
+                    previous: previous_month(date),
+                    next: next_month(date),
+                }
+            }
+
+            GridView::Week => {
+                let start: NaiveDate = week_start(date);
+                GridBounds {
+                    start,
+                    end: start + Duration::weeks(1),
+                    previous: start - Duration::weeks(1),
+                    next: start + Duration::weeks(1),
+                }
+            }
+        }
+    }
+
+    /// Get the heading of the grid containing a date.
+    pub fn title(self, date: NaiveDate) -> String {
+        match self {
+            GridView::Month => date.format("%B %Y").to_string(),
+            GridView::Week => format!("Week of {}", week_start(date).format("%B %-d, %Y")),
+        }
+    }
+}
+
+/// Get the timestamp of the start of a day in a timezone. If midnight is skipped by a daylight
+/// saving change, the day starts at the first valid time after it. If midnight happens twice,
+/// the day starts at the earlier one.
+pub fn start_of_day(date: NaiveDate, timezone: Tz) -> DateTime<Utc> {
+    let midnight: NaiveDateTime = date.and_hms(0, 0, 0);
+    // Daylight saving gaps are at most a few hours, so step forward a minute at a time.
+    (0..24 * 60)
+        .map(|minutes| midnight + Duration::minutes(minutes))
+        .find_map(|local| timezone.from_local_datetime(&local).earliest())
+        .map(|start| start.with_timezone(&Utc))
+        // Every day has a valid time, but fall back to UTC midnight just in case.
+        .unwrap_or(Utc.from_utc_datetime(&midnight))
+}
+
+/// Build the weeks of a calendar grid. Each week is a list of days with the meetings on that
+/// day.
+pub fn make_weeks(
+    view: GridView,
+    date: NaiveDate,
+    today: NaiveDate,
+    timezone: Tz,
+    meetings: &[MeetingsMeetings],
+) -> Vec<Vec<Value>> {
+    let bounds: GridBounds = view.bounds(date);
+
+    let mut weeks: Vec<Vec<Value>> = Vec::new();
+    let mut day: NaiveDate = bounds.start;
+    while day < bounds.end {
+        // Meetings are on every day from their start to their end.
+        let day_meetings: Vec<Value> = meetings
+            .iter()
+            .filter(|meeting| {
+                let start: NaiveDate = meeting.start_date_time.with_timezone(&timezone).date().naive_local();
+                // Meetings ending at midnight do not continue to the next day.
+                let end: NaiveDate = (meeting.end_date_time - Duration::seconds(1))
+                    .with_timezone(&timezone)
+                    .date()
+                    .naive_local();
+                start <= day && day <= end.max(start)
+            })
+            .map(|meeting| {
+                json!({
+                    "meeting_id": meeting.meeting_id,
+                    "title": meeting.title.clone().unwrap_or(meeting.type_.to_string()),
+                    "type": meeting.type_,
+                    "is_draft": meeting.is_draft,
+                    "start": meeting.start_date_time.with_timezone(&timezone).format("%-I:%M %P").to_string(),
+                    "end": meeting.end_date_time.with_timezone(&timezone).format("%-I:%M %P").to_string(),
+                })
+            })
+            .collect();
+
+        if day.weekday().num_days_from_sunday() == 0 {
+            weeks.push(Vec::new());
+        }
+        weeks.last_mut().unwrap().push(json!({
+            "date": day,
+            "day": day.day(),
+            "in_range": view == GridView::Week || day.month() == date.month(),
+            "is_today": day == today,
+            "meetings": day_meetings,
+        }));
+
+        day = day.succ();
+    }
+
+    return weeks;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::start_of_day;
+    use chrono::{LocalResult, NaiveDate, TimeZone, Utc};
+
+    #[test]
+    fn start_of_day_after_skipped_midnight() {
+        // Santiago skips from midnight to 1 AM when daylight saving time starts.
+        let date = NaiveDate::from_ymd(2026, 9, 6);
+        let timezone = chrono_tz::America::Santiago;
+        assert_eq!(
+            timezone.from_local_datetime(&date.and_hms(0, 0, 0)),
+            LocalResult::None
+        );
+        assert_eq!(
+            start_of_day(date, timezone),
+            Utc.ymd(2026, 9, 6).and_hms(4, 0, 0)
+        );
+    }
+
+    #[test]
+    fn start_of_day_at_repeated_midnight() {
+        // Havana repeats the hour after midnight when daylight saving time ends.
+        let start = start_of_day(NaiveDate::from_ymd(2026, 11, 1), chrono_tz::America::Havana);
+        assert_eq!(start, Utc.ymd(2026, 11, 1).and_hms(4, 0, 0));
+    }
+
+    #[test]
+    fn start_of_day_on_normal_day() {
+        let start = start_of_day(
+            NaiveDate::from_ymd(2026, 10, 18),
+            chrono_tz::America::New_York,
+        );
+        assert_eq!(start, Utc.ymd(2026, 10, 18).and_hms(4, 0, 0));
+    }
+}
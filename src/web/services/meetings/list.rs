@@ -1,15 +1,20 @@
 //! List of meetings page.
 
 use crate::api::rcos::meetings::authorization_for::{AuthorizationFor, UserMeetingAuthorization};
+use crate::api::rcos::meetings::calendar::CalendarToken;
 use crate::api::rcos::meetings::get::Meetings;
 use crate::api::rcos::meetings::MeetingType;
+use crate::api::rcos::users::timezone::UserTimezone;
 use crate::error::TelescopeError;
 use crate::templates::page::Page;
 use crate::templates::Template;
 use crate::web::services::auth::identity::Identity;
+use crate::web::services::meetings::calendar::feed_url;
+use crate::web::services::meetings::grid::{self, GridBounds, GridView};
 use actix_web::web::{Query, ServiceConfig};
 use actix_web::HttpRequest;
 use chrono::{Date, DateTime, Duration, Local, NaiveDate, TimeZone, Utc};
+use chrono_tz::Tz;
 
 /// Register the meetings page.
 pub fn register(c: &mut ServiceConfig) -> &mut ServiceConfig {
@@ -28,13 +33,76 @@ struct MeetingsQuery {
     pub end: NaiveDate,
 }
 
+/// Query parameters for the calendar grid views of the meetings page.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+struct GridQuery {
+    /// Show a calendar grid instead of a list of meetings.
+    pub view: Option<GridView>,
+    /// A date on the grid to show. Defaults to today.
+    pub date: Option<NaiveDate>,
+}
+
 /// Meetings page
 #[get("/meetings")]
 async fn meetings_list(
     req: HttpRequest,
     params: Option<Query<MeetingsQuery>>,
+    Query(grid_query): Query<GridQuery>,
     identity: Identity,
 ) -> Result<Page, TelescopeError> {
+    // Is there an RCOS user authenticated?
+    let viewer: Option<_> = identity.get_user_id().await?;
+    // Check if that user can view drafts / certain meeting types.
+    let authorization: UserMeetingAuthorization = AuthorizationFor::get(viewer).await?;
+    let include_drafts: bool = authorization.can_view_drafts();
+    let visible_meeting_types: Vec<MeetingType> = authorization.viewable_types();
+
+    // Get the URL of the viewer's calendar feed, or the public feed if there is no viewer.
+    let calendar_token = match viewer {
+        Some(user_id) => CalendarToken::get(user_id).await?,
+        None => None,
+    };
+
+    let mut template = Template::new(TEMPLATE_PATH);
+    template.fields = json!({
+        "authorization": authorization,
+        "calendar_url": feed_url(calendar_token),
+        "has_calendar_token": calendar_token.is_some(),
+    });
+
+    // Show a calendar grid if one was requested.
+    if let Some(view) = grid_query.view {
+        // Show times in the viewer's timezone.
+        let timezone: Tz = match viewer {
+            Some(user_id) => UserTimezone::get(user_id).await?,
+            None => None,
+        }
+        .unwrap_or(grid::DEFAULT_TIMEZONE);
+
+        let today: NaiveDate = Utc::now().with_timezone(&timezone).date().naive_local();
+        let date: NaiveDate = grid_query.date.unwrap_or(today);
+        let bounds: GridBounds = view.bounds(date);
+
+        let events: Vec<_> = Meetings::get(
+            grid::start_of_day(bounds.start, timezone),
+            grid::start_of_day(bounds.end, timezone),
+            include_drafts,
+            visible_meeting_types,
+        )
+        .await?;
+
+        template["grid"] = json!({
+            "view": view,
+            "title": view.title(date),
+            "previous": bounds.previous,
+            "next": bounds.next,
+            "timezone": timezone.name(),
+            "weeks": grid::make_weeks(view, date, today, timezone, &events),
+        });
+
+        return template.in_page(&req, "RCOS Meetings").await;
+    }
+
     // Resolve parameters to API query variables
     let start: DateTime<Utc> = params
         .as_ref()
@@ -69,13 +137,6 @@ async fn meetings_list(
         // Convert timezone to UTC.
         .with_timezone(&Utc);
 
-    // Is there an RCOS user authenticated?
-    let viewer: Option<_> = identity.get_user_id().await?;
-    // Check if that user can view drafts / certain meeting types.
-    let authorization: UserMeetingAuthorization = AuthorizationFor::get(viewer).await?;
-    let include_drafts: bool = authorization.can_view_drafts();
-    let visible_meeting_types: Vec<MeetingType> = authorization.viewable_types();
-
     // Query the RCOS API to get meeting data.
     let events: Vec<_> = Meetings::get(start, end, include_drafts, visible_meeting_types).await?;
 
@@ -89,12 +150,8 @@ async fn meetings_list(
             end: end.naive_local().date(),
         });
 
-    let mut template = Template::new(TEMPLATE_PATH);
-    template.fields = json!({
-        "meetings": events,
-        "query": query,
-        "authorization": authorization,
-    });
+    template["meetings"] = json!(events);
+    template["query"] = json!(query);
 
     return template.in_page(&req, "RCOS Meetings").await;
 }
@@ -6,10 +6,14 @@ use crate::web::middlewares::authorization::Authorization;
 use actix_web::web::ServiceConfig;
 use uuid::Uuid;
 
+mod attend;
+mod calendar;
 mod create;
 mod delete;
 mod edit;
+mod grid;
 mod list;
+mod slides;
 mod view;
 
 /// Register calendar related services.
@@ -26,6 +30,15 @@ pub fn register(config: &mut ServiceConfig) {
     // Meeting destruction services.
     delete::register(config);
 
+    // Meeting attendance services.
+    attend::register(config);
+
+    // Meeting calendar feeds.
+    calendar::register(config);
+
+    // Meeting slide decks.
+    slides::register(config);
+
     config
         // The meeting viewing endpoint must be registered after the meeting creation endpoint,
         // so that the ID path doesn't match the create path.
@@ -33,7 +46,7 @@ pub fn register(config: &mut ServiceConfig) {
 }
 
 /// Create an authorization middleware based on a meeting authorization function.
-fn make_meeting_auth_middleware<F: 'static + Fn(&UserMeetingAuthorization) -> bool>(
+pub fn make_meeting_auth_middleware<F: 'static + Fn(&UserMeetingAuthorization) -> bool>(
     f: &'static F,
 ) -> Authorization {
     Authorization::new(move |user_id: Uuid| {
@@ -0,0 +1,98 @@
+//! Natively hosted meeting slides.
+//!
+//! Meetings can have markdown for a RevealJS slide deck. Each slide is rendered using the
+//! markdown helper and the deck is served as a full-screen page outside of the usual page
+//! layout.
+
+use crate::api::rcos::meetings::authorization_for::{AuthorizationFor, UserMeetingAuthorization};
+use crate::api::rcos::meetings::get_by_id::{meeting::MeetingMeeting, Meeting};
+use crate::error::TelescopeError;
+use crate::templates::Template;
+use crate::web::services::auth::identity::{AuthenticationCookie, Identity};
+use crate::web::services::meetings::view::check_visible;
+use actix_web::web::{Form, Path, ServiceConfig};
+
+/// The path from the templates directory to the slide deck template.
+const TEMPLATE_PATH: &'static str = "meetings/slides";
+
+/// Register slide services.
+pub fn register(config: &mut ServiceConfig) {
+    config.service(slide_deck).service(preview);
+}
+
+/// Split presentation markdown into slides. Slides are separated by lines containing only
+/// `---`.
+fn split_slides(markdown: &str) -> Vec<String> {
+    let mut slides: Vec<String> = vec![String::new()];
+    for line in markdown.lines() {
+        if line.trim() == "---" {
+            slides.push(String::new());
+        } else {
+            let slide: &mut String = slides.last_mut().unwrap();
+            slide.push_str(line);
+            slide.push('\n');
+        }
+    }
+    return slides;
+}
+
+/// Make the slide deck template for some presentation markdown.
+fn make_deck(title: String, markdown: &str) -> Template {
+    let mut template = Template::new(TEMPLATE_PATH);
+    template.fields = json!({
+        "title": title,
+        "slides": split_slides(markdown),
+    });
+    return template;
+}
+
+/// Full-screen slide deck for a meeting.
+#[get("/meeting/{meeting_id}/slides")]
+async fn slide_deck(
+    Path(meeting_id): Path<i64>,
+    identity: Identity,
+) -> Result<Template, TelescopeError> {
+    // Slides are visible to the same users as the meeting.
+    let viewer: Option<_> = identity.get_user_id().await?;
+    let authorization: UserMeetingAuthorization = AuthorizationFor::get(viewer).await?;
+    let meeting: MeetingMeeting =
+        Meeting::get(meeting_id)
+            .await?
+            .ok_or(TelescopeError::resource_not_found(
+                "Meeting Not Found",
+                "Could not find a meeting for this ID.",
+            ))?;
+    check_visible(&meeting, &authorization)?;
+
+    let markdown: &str = meeting
+        .presentation_markdown
+        .as_deref()
+        .filter(|markdown| !markdown.trim().is_empty())
+        .ok_or(TelescopeError::resource_not_found(
+            "Slides Not Found",
+            "This meeting does not have slides hosted on Telescope.",
+        ))?;
+
+    return Ok(make_deck(meeting.title(), markdown));
+}
+
+/// Form submitted by the meeting edit page to preview slides.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct PreviewForm {
+    #[serde(default)]
+    presentation_markdown: String,
+}
+
+/// Endpoint to render a slide deck preview while editing a meeting.
+#[post("/meeting/slides/preview")]
+async fn preview(
+    auth: AuthenticationCookie,
+    Form(form): Form<PreviewForm>,
+) -> Result<Template, TelescopeError> {
+    // Only signed in users can render previews.
+    auth.get_user_id_or_error().await?;
+    return Ok(make_deck(
+        "Slides Preview".into(),
+        &form.presentation_markdown,
+    ));
+}
@@ -9,7 +9,7 @@ use crate::templates::Template;
 use crate::web::services::auth::identity::Identity;
 use actix_web::web::Path;
 use actix_web::HttpRequest;
-use chrono::{Local, TimeZone};
+use chrono::{Local, TimeZone, Utc};
 
 /// The path from the templates directory to this template.
 const TEMPLATE_PATH: &'static str = "meetings/page";
@@ -36,31 +36,15 @@ pub async fn meeting(
     }
 
     // Unwrap the meeting object.
-    let meeting: MeetingMeeting = meeting.unwrap();
+    let mut meeting: MeetingMeeting = meeting.unwrap();
     // Make sure that the meeting is visible to the user.
-    // First check for draft status.
+    check_visible(&meeting, &authorization)?;
     let meeting_host: Option<_> = meeting.host.as_ref().map(|host| host.id);
     let can_edit: bool = authorization.can_edit(meeting_host);
-    if !can_edit && meeting.is_draft && !authorization.can_view_drafts() {
-        return Err(TelescopeError::BadRequest {
-            header: "Meeting Not Visible".into(),
-            message: "This meeting is currently marked as a draft and is only visible to \
-            coordinators and faculty advisors. If you believe this is in error, please \
-            contact a coordinator."
-                .into(),
-            show_status_code: false,
-        });
-    }
 
-    // Then check the meeting variant.
-    if !authorization.can_view(meeting.type_) {
-        return Err(TelescopeError::BadRequest {
-            header: "Meeting Access Restricted".into(),
-            message: "Access to this meeting is restricted to mentors or coordinators. If you \
-            think this is in error, please contact a coordinator."
-                .into(),
-            show_status_code: false,
-        });
+    // Hide the attendance code from anyone who cannot edit the meeting.
+    if !can_edit {
+        meeting.attendance_code = None;
     }
 
     // Create dynamic OGP tags and start with default so all other fields are correct
@@ -130,7 +114,9 @@ pub async fn meeting(
     let mut template = Template::new(TEMPLATE_PATH);
     template.fields = json!({
         "meeting": &meeting,
-        "auth": authorization
+        "auth": authorization,
+        "can_edit": can_edit,
+        "is_ongoing": meeting.start_date_time <= Utc::now() && Utc::now() <= meeting.end_date_time
     });
 
     // Build page around meeting template.
@@ -140,3 +126,37 @@ pub async fn meeting(
     // Return page.
     return Ok(page);
 }
+
+/// Check that a meeting is visible to a user. Drafts are only visible to users who can edit
+/// them or view drafts, and some meeting types are restricted to mentors or coordinators.
+pub fn check_visible(
+    meeting_data: &MeetingMeeting,
+    authorization: &UserMeetingAuthorization,
+) -> Result<(), TelescopeError> {
+    // First check for draft status.
+    let meeting_host: Option<_> = meeting_data.host.as_ref().map(|host| host.id);
+    let can_edit: bool = authorization.can_edit(meeting_host);
+    if !can_edit && meeting_data.is_draft && !authorization.can_view_drafts() {
+        return Err(TelescopeError::BadRequest {
+            header: "Meeting Not Visible".into(),
+            message: "This meeting is currently marked as a draft and is only visible to \
+            coordinators and faculty advisors. If you believe this is in error, please \
+            contact a coordinator."
+                .into(),
+            show_status_code: false,
+        });
+    }
+
+    // Then check the meeting variant.
+    if !authorization.can_view(meeting_data.type_) {
+        return Err(TelescopeError::BadRequest {
+            header: "Meeting Access Restricted".into(),
+            message: "Access to this meeting is restricted to mentors or coordinators. If you \
+            think this is in error, please contact a coordinator."
+                .into(),
+            show_status_code: false,
+        });
+    }
+
+    return Ok(());
+}
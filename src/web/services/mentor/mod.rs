@@ -0,0 +1,176 @@
+//! Services for the mentor panel.
+//!
+//! Enrolled students apply to mentor from the panel index, which also lists the small groups
+//! that approved mentors are assigned to. Coordinators and faculty advisors review applications.
+//! Mentors propose workshops from here as well.
+
+mod proposals;
+mod workshops;
+
+use crate::api::rcos::mentors::{ApplyToMentor, MentorPanel};
+use crate::api::rcos::users::navbar_auth::Authentication;
+use crate::error::TelescopeError;
+use crate::templates::page::Page;
+use crate::templates::Template;
+use crate::web::middlewares::authorization::{Authorization, AuthorizationResult};
+use crate::web::services::auth::identity::AuthenticationCookie;
+use actix_web::http::header::LOCATION;
+use actix_web::web as aweb;
+use actix_web::web::{Form, ServiceConfig};
+use actix_web::{HttpRequest, HttpResponse};
+use futures::future::LocalBoxFuture;
+use uuid::Uuid;
+
+/// The handlebars template for the mentor panel.
+const TEMPLATE_PATH: &'static str = "mentor/index";
+
+/// Check that a user can review mentor applications. This is current coordinators and admins
+/// (faculty advisors and sysadmins).
+fn reviewer_authorization(user_id: Uuid) -> LocalBoxFuture<'static, AuthorizationResult> {
+    Box::pin(async move {
+        let auth = Authentication::get(user_id).await?;
+
+        // Forbid access unless the user is coordinating or an admin.
+        if !(auth.is_coordinating() || auth.is_admin()) {
+            Err(TelescopeError::Forbidden)
+        } else {
+            Ok(())
+        }
+    })
+}
+
+/// Check that a user can propose workshops. This is current mentors and coordinators.
+fn mentor_authorization(user_id: Uuid) -> LocalBoxFuture<'static, AuthorizationResult> {
+    Box::pin(async move {
+        let auth = Authentication::get(user_id).await?;
+
+        // Forbid access unless the user is mentoring or coordinating.
+        if !(auth.is_mentoring() || auth.is_coordinating()) {
+            Err(TelescopeError::Forbidden)
+        } else {
+            Ok(())
+        }
+    })
+}
+
+/// Register mentor panel services.
+pub fn register(config: &mut ServiceConfig) {
+    // Create reviewer authorization middleware.
+    let reviewer_authorization_middleware: Authorization =
+        Authorization::new(reviewer_authorization);
+
+    // Mentor panel index page and application form. Available to all authenticated users.
+    config.service(index).service(apply);
+
+    // Route application review through the reviewer scope.
+    config.service(
+        aweb::scope("/mentor/proposals")
+            // Verify that the viewer can review applications.
+            .wrap(reviewer_authorization_middleware)
+            .configure(proposals::register),
+    );
+
+    // Route workshop proposals through the mentor scope.
+    config.service(
+        aweb::scope("/mentor/workshops")
+            // Verify that the viewer is a current mentor or coordinator.
+            .wrap(Authorization::new(mentor_authorization))
+            .configure(workshops::register),
+    );
+}
+
+/// Create the mentor panel for a user.
+async fn panel(user_id: Uuid) -> Result<Template, TelescopeError> {
+    let panel = MentorPanel::get(user_id)
+        .await?
+        // The account should exist, since the user is authenticated.
+        .ok_or(TelescopeError::ise("Viewer's account does not exist."))?;
+
+    let viewer = Authentication::get(user_id).await?;
+
+    let mut template = Template::new(TEMPLATE_PATH);
+    template.fields = json!({
+        "panel": panel,
+        "can_review": viewer.is_coordinating() || viewer.is_admin(),
+        "can_propose_workshops": viewer.is_mentoring() || viewer.is_coordinating(),
+    });
+    return Ok(template);
+}
+
+/// Mentor panel index.
+#[get("/mentor")]
+async fn index(req: HttpRequest, auth: AuthenticationCookie) -> Result<Page, TelescopeError> {
+    let user_id: Uuid = auth.get_user_id_or_error().await?;
+    return panel(user_id).await?.in_page(&req, "Mentor").await;
+}
+
+/// Form submitted by students applying to mentor.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct ApplicationForm {
+    /// The semester the student wants to mentor in.
+    semester: String,
+    reason: String,
+    skillset: String,
+}
+
+/// Endpoint to apply to mentor.
+#[post("/mentor/apply")]
+async fn apply(
+    req: HttpRequest,
+    auth: AuthenticationCookie,
+    Form(form): Form<ApplicationForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    let user_id: Uuid = auth.get_user_id_or_error().await?;
+
+    // Create a panel to send back if there are any issues.
+    let mut return_form: Template = panel(user_id).await?;
+    return_form["selections"] = json!(&form);
+
+    // Find the enrollment for the selected semester.
+    let enrollment = return_form["panel"]["enrollments"]
+        .as_array()
+        .and_then(|enrollments| {
+            enrollments
+                .iter()
+                .find(|e| e["semester"]["semester_id"] == form.semester.as_str())
+        })
+        .cloned();
+
+    match enrollment {
+        None => {
+            return_form["issues"]["semester"] =
+                json!("You must be enrolled in a current semester to apply.");
+        }
+        // Approved applications are final.
+        Some(enrollment) if enrollment["mentor_proposal"]["is_approved"] == true => {
+            return_form["issues"]["semester"] =
+                json!("Your application for this semester was already approved.");
+        }
+        _ => {}
+    }
+
+    if form.reason.trim().is_empty() {
+        return_form["issues"]["reason"] = json!("Please explain why you want to mentor.");
+    }
+
+    if form.skillset.trim().is_empty() {
+        return_form["issues"]["skillset"] = json!("Please describe your skillset.");
+    }
+
+    // Return the form if there are any issues.
+    if !return_form["issues"].is_null() {
+        let page = return_form.in_page(&req, "Mentor").await?;
+        return Err(TelescopeError::InvalidForm(page));
+    }
+
+    ApplyToMentor::execute(
+        form.semester,
+        user_id,
+        form.reason.trim().to_string(),
+        form.skillset.trim().to_string(),
+    )
+    .await?;
+
+    // Send the user back to the panel, which shows the status of their application.
+    return Ok(HttpResponse::Found().header(LOCATION, "/mentor").finish());
+}
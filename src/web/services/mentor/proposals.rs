@@ -0,0 +1,76 @@
+//! Mentor application review services.
+
+use crate::api::rcos::mentors::{MentorProposals, ReviewMentorProposal};
+use crate::error::TelescopeError;
+use crate::templates::page::Page;
+use crate::templates::Template;
+use crate::web::services::auth::identity::AuthenticationCookie;
+use actix_web::http::header::LOCATION;
+use actix_web::web::{Form, Path, ServiceConfig};
+use actix_web::{HttpRequest, HttpResponse};
+use uuid::Uuid;
+
+/// The handlebars template for the application review queue.
+const TEMPLATE_PATH: &'static str = "mentor/proposals";
+
+/// Register mentor application review services. These are registered in the reviewer scope.
+pub fn register(config: &mut ServiceConfig) {
+    config.service(queue).service(review);
+}
+
+/// Page listing the mentor applications for the current semesters.
+#[get("")]
+async fn queue(req: HttpRequest) -> Result<Page, TelescopeError> {
+    let mut template = Template::new(TEMPLATE_PATH);
+    template.fields = json!({
+        "proposals": MentorProposals::get().await?,
+    });
+    return template.in_page(&req, "Mentor Applications").await;
+}
+
+/// Form submitted by reviewers to approve or reject an application.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct ReviewForm {
+    /// Either "approve" or "reject".
+    decision: String,
+
+    #[serde(default)]
+    reviewer_comments: String,
+}
+
+/// Endpoint to approve or reject a mentor application.
+#[post("/{semester_id}/{user_id}/review")]
+async fn review(
+    auth: AuthenticationCookie,
+    Path((semester_id, user_id)): Path<(String, Uuid)>,
+    Form(form): Form<ReviewForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    let reviewer_id: Uuid = auth.get_user_id_or_error().await?;
+
+    let is_approved: bool = match form.decision.as_str() {
+        "approve" => true,
+        "reject" => false,
+        _ => {
+            return Err(TelescopeError::BadRequest {
+                header: "Invalid Decision".into(),
+                message: "Applications can only be approved or rejected.".into(),
+                show_status_code: false,
+            })
+        }
+    };
+
+    let comments: Option<String> = (!form.reviewer_comments.trim().is_empty())
+        .then(|| form.reviewer_comments.trim().to_string());
+
+    ReviewMentorProposal::execute(semester_id, user_id, reviewer_id, is_approved, comments)
+        .await?
+        .ok_or(TelescopeError::resource_not_found(
+            "Application Not Found",
+            "Could not find a mentor application by this user for this semester.",
+        ))?;
+
+    // Send the reviewer back to the queue.
+    return Ok(HttpResponse::Found()
+        .header(LOCATION, "/mentor/proposals")
+        .finish());
+}
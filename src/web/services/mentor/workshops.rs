@@ -0,0 +1,167 @@
+//! Workshop proposal services for mentors.
+//!
+//! Mentors propose a workshop with three preferred times. Coordinators review proposals from
+//! the coordinator panel and approving one schedules it as a bonus session.
+
+use crate::api::rcos::workshops::{propose_workshop, ProposeWorkshop, WorkshopContext};
+use crate::error::TelescopeError;
+use crate::templates::page::Page;
+use crate::templates::Template;
+use crate::web::local_timestamp;
+use crate::web::services::auth::identity::AuthenticationCookie;
+use actix_web::http::header::LOCATION;
+use actix_web::web::{Form, ServiceConfig};
+use actix_web::{HttpRequest, HttpResponse};
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
+
+/// The handlebars template for the workshop proposal page.
+const TEMPLATE_PATH: &'static str = "mentor/workshops";
+
+/// Register workshop proposal services. These are registered in the mentor scope.
+pub fn register(config: &mut ServiceConfig) {
+    config.service(workshops_page).service(propose);
+}
+
+/// Create the workshop proposal page for a user.
+async fn workshops_form(user_id: Uuid) -> Result<Template, TelescopeError> {
+    let context = WorkshopContext::get(user_id)
+        .await?
+        // The account should exist, since the user is authenticated.
+        .ok_or(TelescopeError::ise("Viewer's account does not exist."))?;
+
+    let mut form = Template::new(TEMPLATE_PATH);
+    form.fields = json!({
+        "context": context,
+    });
+    return Ok(form);
+}
+
+/// Page listing a mentor's workshop proposals with a form to propose another.
+#[get("")]
+async fn workshops_page(
+    req: HttpRequest,
+    auth: AuthenticationCookie,
+) -> Result<Page, TelescopeError> {
+    let user_id: Uuid = auth.get_user_id_or_error().await?;
+    return workshops_form(user_id)
+        .await?
+        .in_page(&req, "Workshop Proposals")
+        .await;
+}
+
+/// Form submitted by mentors to propose a workshop.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct ProposalForm {
+    /// The semester the workshop is for.
+    semester: String,
+    topic: String,
+    title: String,
+    qualifications: String,
+
+    first_choice_date: NaiveDate,
+    /// Cannot be a [`chrono::NaiveTime`], since seconds are not included.
+    first_choice_time: String,
+
+    second_choice_date: NaiveDate,
+    /// Cannot be a [`chrono::NaiveTime`], since seconds are not included.
+    second_choice_time: String,
+
+    third_choice_date: NaiveDate,
+    /// Cannot be a [`chrono::NaiveTime`], since seconds are not included.
+    third_choice_time: String,
+}
+
+/// Endpoint to propose a workshop.
+#[post("")]
+async fn propose(
+    req: HttpRequest,
+    auth: AuthenticationCookie,
+    Form(form): Form<ProposalForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    let user_id: Uuid = auth.get_user_id_or_error().await?;
+
+    // Create a form to send back if there are any issues.
+    let mut return_form: Template = workshops_form(user_id).await?;
+    return_form["selections"] = json!(&form);
+
+    // Make sure the user is enrolled in the selected semester.
+    let is_enrolled: bool = return_form["context"]["enrollments"]
+        .as_array()
+        .map(|enrollments| {
+            enrollments
+                .iter()
+                .any(|e| e["semester"]["semester_id"] == form.semester.as_str())
+        })
+        .unwrap_or(false);
+    if !is_enrolled {
+        return_form["issues"]["semester"] =
+            json!("Please select a current semester you are enrolled in.");
+    }
+
+    // Check the text fields.
+    for (field, value) in [
+        ("topic", &form.topic),
+        ("title", &form.title),
+        ("qualifications", &form.qualifications),
+    ] {
+        if value.trim().is_empty() {
+            return_form["issues"][field] = json!("This field is required.");
+        }
+    }
+
+    // Convert the preferred times.
+    let now: DateTime<Utc> = Utc::now();
+    let mut choices: Vec<DateTime<Utc>> = Vec::with_capacity(3);
+    for (field, date, time) in [
+        (
+            "first_choice",
+            form.first_choice_date,
+            &form.first_choice_time,
+        ),
+        (
+            "second_choice",
+            form.second_choice_date,
+            &form.second_choice_time,
+        ),
+        (
+            "third_choice",
+            form.third_choice_date,
+            &form.third_choice_time,
+        ),
+    ] {
+        match local_timestamp(date, time) {
+            None => return_form["issues"][field] = json!("Could not parse time."),
+            Some(timestamp) if timestamp <= now => {
+                return_form["issues"][field] = json!("Preferred times must be in the future.")
+            }
+            Some(timestamp) => choices.push(timestamp),
+        }
+    }
+
+    // Return the form if there are any issues.
+    if !return_form["issues"].is_null() {
+        let page = return_form.in_page(&req, "Workshop Proposals").await?;
+        return Err(TelescopeError::InvalidForm(page));
+    }
+
+    ProposeWorkshop::execute(propose_workshop::Variables {
+        semester_id: form.semester,
+        user_id,
+        topic: form.topic.trim().to_string(),
+        title: form.title.trim().to_string(),
+        qualifications: form.qualifications.trim().to_string(),
+        first_choice_at: choices[0],
+        second_choice_at: choices[1],
+        third_choice_at: choices[2],
+    })
+    .await?
+    .ok_or(TelescopeError::ise(
+        "Workshop proposal creation did not return ID.",
+    ))?;
+
+    // Send the mentor back to their proposals.
+    return Ok(HttpResponse::Found()
+        .header(LOCATION, "/mentor/workshops")
+        .finish());
+}
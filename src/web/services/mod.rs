@@ -3,12 +3,16 @@
 use actix_web::web::ServiceConfig;
 
 mod admin;
+mod announcements;
 mod coordinate;
 pub mod auth;
+mod engage;
 mod index;
 pub mod meetings;
+mod mentor;
 pub mod not_found;
 mod projects;
+mod small_groups;
 pub mod user;
 
 /// Register all of the routes to the actix app.
@@ -31,6 +35,18 @@ pub fn register(config: &mut ServiceConfig) {
     // Coordinator panel services.
     coordinate::register(config);
 
+    // Mentor panel services.
+    mentor::register(config);
+
+    // Student engagement panel services.
+    engage::register(config);
+
+    // Announcement archive.
+    announcements::register(config);
+
+    // Small group pages.
+    small_groups::register(config);
+
     config
         // Homepage
         .service(index::index);
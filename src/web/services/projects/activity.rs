@@ -0,0 +1,154 @@
+//! GitHub activity on project repositories, used by coordinators as evidence for grading.
+
+use crate::api::github::repositories::activity::{
+    parse_repository_url, ActivitySummary, RepositoryActivity,
+};
+use crate::api::github::telescope_access_token;
+use crate::api::rcos::projects::get_by_id::project::ProjectProject;
+use crate::api::rcos::projects::join_requests::project_members::ProjectMembersSemesters;
+use crate::api::rcos::users::accounts::github_users::GitHubUsers;
+use crate::error::TelescopeError;
+use chrono::{DateTime, Duration, Utc};
+use dashmap::DashMap;
+use futures::future::join_all;
+use oauth2::AccessToken;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// How long repository activity is cached for.
+const CACHE_MINUTES: i64 = 60;
+
+lazy_static! {
+    /// Cached repository activity, keyed on the repository's owner and name and the semester ID,
+    /// with the time each record expires.
+    static ref ACTIVITY_CACHE: DashMap<(String, String), (Option<ActivitySummary>, DateTime<Utc>)> =
+        DashMap::new();
+}
+
+/// Get the activity on a repository during a semester, using the cached record if there is an
+/// unexpired one. Only successful responses are cached.
+async fn cached_activity(
+    token: &AccessToken,
+    owner: &str,
+    name: &str,
+    semester: &ProjectMembersSemesters,
+) -> Result<Option<ActivitySummary>, TelescopeError> {
+    let key = (format!("{}/{}", owner, name), semester.semester_id.clone());
+
+    // Check for an unexpired cached record.
+    if let Some(record) = ACTIVITY_CACHE.get(&key) {
+        if record.value().1 > Utc::now() {
+            return Ok(record.value().0.clone());
+        }
+    }
+
+    let summary: Option<ActivitySummary> = RepositoryActivity::get(
+        token,
+        owner.to_string(),
+        name.to_string(),
+        semester.start_date,
+        semester.end_date,
+    )
+    .await?;
+
+    let expiration_time: DateTime<Utc> = Utc::now() + Duration::minutes(CACHE_MINUTES);
+    ACTIVITY_CACHE.insert(key, (summary.clone(), expiration_time));
+    return Ok(summary);
+}
+
+/// Get the activity on a project's GitHub repositories for each semester it had members.
+/// Return `None` if the project has no GitHub repositories or Telescope is not configured
+/// with a GitHub access token. Repository activity is cached for [`CACHE_MINUTES`] minutes.
+pub async fn project_activity(
+    project: &ProjectProject,
+    semesters: &[ProjectMembersSemesters],
+) -> Result<Option<Value>, TelescopeError> {
+    let token = match telescope_access_token() {
+        Some(token) => token,
+        None => return Ok(None),
+    };
+
+    let repositories: Vec<(String, String)> = project
+        .project_repositories
+        .iter()
+        .filter_map(|repo| parse_repository_url(repo.url.as_str()))
+        .collect();
+
+    if repositories.is_empty() {
+        return Ok(None);
+    }
+
+    // Query every repository for every semester at once.
+    let queries = semesters.iter().flat_map(|semester| {
+        let token = &token;
+        repositories.iter().map(move |(owner, name)| async move {
+            let result = cached_activity(token, owner, name, semester).await;
+            (semester, format!("{}/{}", owner, name), result)
+        })
+    });
+    let results = join_all(queries).await;
+
+    // Map the contributors to RCOS users through their linked GitHub accounts.
+    let mut github_ids: Vec<String> = results
+        .iter()
+        .filter_map(|(_, _, result)| result.as_ref().ok()?.as_ref())
+        .flat_map(|summary| summary.contributors.iter().map(|c| c.github_id.clone()))
+        .collect();
+    github_ids.sort();
+    github_ids.dedup();
+
+    let rcos_users: HashMap<String, Value> = GitHubUsers::get(github_ids)
+        .await?
+        .into_iter()
+        .map(|account| (account.account_id, json!(account.user)))
+        .collect();
+
+    // Build the activity for each semester.
+    let activity: Vec<Value> = semesters
+        .iter()
+        .map(|semester| {
+            let repositories: Vec<Value> = results
+                .iter()
+                .filter(|(s, _, _)| s.semester_id == semester.semester_id)
+                .map(|(_, name, result)| match result {
+                    Ok(Some(summary)) => summary_json(summary, &rcos_users),
+                    Ok(None) => json!({
+                        "name_with_owner": name,
+                        "error": "Repository not found.",
+                    }),
+                    Err(err) => {
+                        warn!("Could not get GitHub activity for {}: {}", name, err);
+                        json!({
+                            "name_with_owner": name,
+                            "error": "Could not reach the GitHub API.",
+                        })
+                    }
+                })
+                .collect();
+
+            json!({
+                "title": semester.title,
+                "repositories": repositories,
+            })
+        })
+        .collect();
+
+    return Ok(Some(json!(activity)));
+}
+
+/// Convert a repository activity summary to JSON for the project page, with the RCOS user
+/// (if any) of each contributor.
+fn summary_json(summary: &ActivitySummary, rcos_users: &HashMap<String, Value>) -> Value {
+    let mut value: Value = json!(summary);
+    value["contributors"] = summary
+        .contributors
+        .iter()
+        .map(|contributor| {
+            json!({
+                "login": contributor.login,
+                "user": rcos_users.get(&contributor.github_id),
+            })
+        })
+        .collect();
+    return value;
+}
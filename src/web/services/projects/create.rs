@@ -132,11 +132,12 @@ async fn submit_project(
     // or feedback.
     let created_project_id: i64 = CreateProject::execute(
         title,
-        Some(stack),
-        Some(repository_urls),
+        stack,
+        repository_urls,
         Some(homepage_url),
         Some(description),
         Some(cover_image_url),
+        None,
     )
     .await?
     .ok_or(TelescopeError::ise(
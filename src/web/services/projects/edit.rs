@@ -0,0 +1,194 @@
+//! Project edit form and services.
+//!
+//! Projects can be edited by coordinators, faculty advisors, and the current leads of the
+//! project.
+
+use crate::api::rcos::projects::authorization_for::AuthorizationFor;
+use crate::api::rcos::projects::edit::EditProject;
+use crate::api::rcos::projects::get_by_id::{project::ProjectProject, Project};
+use crate::error::TelescopeError;
+use crate::templates::page::Page;
+use crate::templates::Template;
+use crate::web::services::auth::identity::AuthenticationCookie;
+use actix_web::http::header::LOCATION;
+use actix_web::web::{Form, Path, ServiceConfig};
+use actix_web::{HttpRequest, HttpResponse};
+use url::Url;
+use uuid::Uuid;
+
+/// The handlebars template for the project edit form.
+const TEMPLATE_PATH: &'static str = "projects/edit";
+
+/// Register project edit services.
+pub fn register(config: &mut ServiceConfig) {
+    config.service(edit_page).service(submit_edit);
+}
+
+/// Get a project by ID if the user is allowed to edit it.
+async fn get_editable_project(
+    user_id: Uuid,
+    project_id: i64,
+) -> Result<ProjectProject, TelescopeError> {
+    let project: ProjectProject =
+        Project::get(project_id)
+            .await?
+            .ok_or(TelescopeError::resource_not_found(
+                "Project Not Found",
+                "Could not find a Project for this ID.",
+            ))?;
+
+    // Check that the user can edit this project.
+    if !AuthorizationFor::get(Some(user_id))
+        .await?
+        .can_edit(project_id)
+    {
+        return Err(TelescopeError::Forbidden);
+    }
+
+    return Ok(project);
+}
+
+/// Create the edit form for a project.
+fn edit_form(project: &ProjectProject) -> Template {
+    let mut form = Template::new(TEMPLATE_PATH);
+    form.fields = json!({
+        "project": project,
+    });
+    return form;
+}
+
+/// Page to edit a project.
+#[get("/project/{project_id}/edit")]
+async fn edit_page(
+    req: HttpRequest,
+    auth: AuthenticationCookie,
+    Path(project_id): Path<i64>,
+) -> Result<Page, TelescopeError> {
+    let user_id: Uuid = auth.get_user_id_or_error().await?;
+    let project: ProjectProject = get_editable_project(user_id, project_id).await?;
+
+    let mut form: Template = edit_form(&project);
+    // Fill the form with the project's current data.
+    form["selections"] = json!({
+        "title": &project.title,
+        "description": &project.description,
+        "homepage_url": &project.homepage_url,
+        "cover_image_url": &project.cover_image_url,
+        "stack": project
+            .project_stack
+            .iter()
+            .map(|item| item.language.as_str())
+            .collect::<Vec<&str>>()
+            .join(", "),
+        "repositories": project
+            .project_repositories
+            .iter()
+            .map(|repo| repo.url.as_str())
+            .collect::<Vec<&str>>()
+            .join("\n"),
+    });
+
+    return form.in_page(&req, "Edit Project").await;
+}
+
+/// Form submitted to edit a project.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct EditForm {
+    title: String,
+
+    #[serde(default)]
+    description: String,
+
+    #[serde(default)]
+    homepage_url: String,
+
+    #[serde(default)]
+    cover_image_url: String,
+
+    /// Comma separated list of technologies.
+    #[serde(default)]
+    stack: String,
+
+    /// Repository URLs, one per line.
+    #[serde(default)]
+    repositories: String,
+}
+
+/// Trim a string and convert it to `None` if it is empty.
+fn non_empty(string: &str) -> Option<String> {
+    let trimmed = string.trim();
+    (!trimmed.is_empty()).then(|| trimmed.to_string())
+}
+
+/// Endpoint to submit a project edit.
+#[post("/project/{project_id}/edit")]
+async fn submit_edit(
+    req: HttpRequest,
+    auth: AuthenticationCookie,
+    Path(project_id): Path<i64>,
+    Form(form): Form<EditForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    let user_id: Uuid = auth.get_user_id_or_error().await?;
+    let project: ProjectProject = get_editable_project(user_id, project_id).await?;
+
+    // Create a form to send back if there are any issues.
+    let mut return_form: Template = edit_form(&project);
+    return_form["selections"] = json!(&form);
+
+    let title: Option<String> = non_empty(&form.title);
+    if title.is_none() {
+        return_form["issues"]["title"] = json!("Projects need a title.");
+    }
+
+    let homepage_url: Option<String> = non_empty(&form.homepage_url);
+    if homepage_url
+        .as_deref()
+        .map(Url::parse)
+        .map_or(false, |r| r.is_err())
+    {
+        return_form["issues"]["homepage_url"] = json!("Please enter a valid URL.");
+    }
+
+    let cover_image_url: Option<String> = non_empty(&form.cover_image_url);
+    if cover_image_url
+        .as_deref()
+        .map(Url::parse)
+        .map_or(false, |r| r.is_err())
+    {
+        return_form["issues"]["cover_image_url"] = json!("Please enter a valid URL.");
+    }
+
+    let stack: Vec<String> = form.stack.split(',').filter_map(non_empty).collect();
+
+    let repositories: Vec<String> = form.repositories.lines().filter_map(non_empty).collect();
+    if let Some(invalid) = repositories.iter().find(|url| Url::parse(url).is_err()) {
+        return_form["issues"]["repositories"] =
+            json!(format!("\"{}\" is not a valid URL.", invalid));
+    }
+
+    // Return the form if there are any issues.
+    if !return_form["issues"].is_null() {
+        let page = return_form.in_page(&req, "Edit Project").await?;
+        return Err(TelescopeError::InvalidForm(page));
+    }
+
+    EditProject::execute(
+        project_id,
+        title.unwrap(),
+        form.description.trim().to_string(),
+        homepage_url,
+        cover_image_url,
+        stack,
+        repositories,
+    )
+    .await?
+    .ok_or(TelescopeError::resource_not_found(
+        "Project Not Found",
+        "Could not find a Project for this ID.",
+    ))?;
+
+    // Send the user back to the project page.
+    return Ok(HttpResponse::Found()
+        .header(LOCATION, format!("/project/{}", project_id))
+        .finish());
+}
@@ -0,0 +1,117 @@
+//! Services for students to request to join projects and for project leads to handle those
+//! requests.
+
+use crate::api::rcos::projects::authorization_for::AuthorizationFor;
+use crate::api::rcos::projects::join_requests::{
+    ApproveJoinRequest, DenyJoinRequest, JoinRequestContext, RequestToJoin,
+};
+use crate::error::TelescopeError;
+use crate::web::services::auth::identity::AuthenticationCookie;
+use actix_web::http::header::LOCATION;
+use actix_web::web::{Form, Path, ServiceConfig};
+use actix_web::HttpResponse;
+use uuid::Uuid;
+
+/// Register join request services.
+pub fn register(config: &mut ServiceConfig) {
+    config.service(request_to_join).service(review);
+}
+
+/// Redirect back to a project's page.
+fn redirect_to_project(project_id: i64) -> HttpResponse {
+    return HttpResponse::Found()
+        .header(LOCATION, format!("/project/{}", project_id))
+        .finish();
+}
+
+/// Endpoint for students to request to join a project for the current semester.
+#[post("/project/{project_id}/join")]
+async fn request_to_join(
+    auth: AuthenticationCookie,
+    Path(project_id): Path<i64>,
+) -> Result<HttpResponse, TelescopeError> {
+    let user_id: Uuid = auth.get_user_id_or_error().await?;
+
+    // Find the most recent current semester the user is enrolled in.
+    let semesters = JoinRequestContext::get(project_id, user_id).await?;
+    let (semester, enrollment) =
+        JoinRequestContext::join_semester(&semesters).ok_or(TelescopeError::BadRequest {
+            header: "Not Enrolled".into(),
+            message: "You must be enrolled in a current semester to join a project.".into(),
+            show_status_code: false,
+        })?;
+
+    if enrollment.project_id == Some(project_id) {
+        return Err(TelescopeError::BadRequest {
+            header: "Already a Member".into(),
+            message: format!("You are already on this project for {}.", semester.title),
+            show_status_code: false,
+        });
+    }
+
+    RequestToJoin::execute(semester.semester_id.clone(), user_id, project_id).await?;
+    return Ok(redirect_to_project(project_id));
+}
+
+/// Form submitted by project leads to handle a join request.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct ReviewForm {
+    /// Either "approve" or "deny".
+    decision: String,
+}
+
+/// Endpoint for project leads and coordinators to approve or deny a request to join a project.
+#[post("/project/{project_id}/join_requests/{semester_id}/{user_id}/review")]
+async fn review(
+    auth: AuthenticationCookie,
+    Path((project_id, semester_id, user_id)): Path<(i64, String, Uuid)>,
+    Form(form): Form<ReviewForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    let reviewer_id: Uuid = auth.get_user_id_or_error().await?;
+
+    // Project leads and staff manage project membership.
+    if !AuthorizationFor::get(Some(reviewer_id))
+        .await?
+        .can_edit(project_id)
+    {
+        return Err(TelescopeError::Forbidden);
+    }
+
+    // Make sure there is a pending request for a current semester.
+    let is_pending: bool = JoinRequestContext::get(project_id, reviewer_id)
+        .await?
+        .iter()
+        .filter(|semester| semester.semester_id == semester_id)
+        .flat_map(|semester| semester.project_join_requests.iter())
+        .any(|request| request.user.id == user_id);
+
+    if !is_pending {
+        return Err(TelescopeError::resource_not_found(
+            "Request Not Found",
+            "Could not find a pending request by this user to join this project.",
+        ));
+    }
+
+    match form.decision.as_str() {
+        "approve" => {
+            ApproveJoinRequest::execute(semester_id, user_id, project_id)
+                .await?
+                .ok_or(TelescopeError::resource_not_found(
+                    "Enrollment Not Found",
+                    "Could not find an enrollment for this user in this semester.",
+                ))?;
+        }
+        "deny" => {
+            DenyJoinRequest::execute(semester_id, user_id, project_id).await?;
+        }
+        _ => {
+            return Err(TelescopeError::BadRequest {
+                header: "Invalid Decision".into(),
+                message: "Join requests can only be approved or denied.".into(),
+                show_status_code: false,
+            })
+        }
+    }
+
+    return Ok(redirect_to_project(project_id));
+}
@@ -6,7 +6,12 @@ use crate::api::rcos::projects::authorization_for::{AuthorizationFor, UserProjec
 use crate::error::TelescopeError;
 use uuid::Uuid;
 
+mod activity;
+mod edit;
+mod join_requests;
 mod list;
+mod pitch;
+mod presentations;
 mod view;
 // mod create;
 
@@ -14,6 +19,10 @@ mod view;
 pub fn register(conf: &mut ServiceConfig) {
     conf.service(list::get);
     conf.service(view::project);
+    pitch::register(conf);
+    edit::register(conf);
+    join_requests::register(conf);
+    presentations::register(conf);
 }
 
 /// Create an authorization middleware based on a project authorization function.
@@ -0,0 +1,180 @@
+//! Project pitch submission services.
+//!
+//! At the start of a semester, enrolled students pitch a new project or pitch an existing
+//! project to be continued. Pitches are reviewed by coordinators from the coordinator panel.
+
+use crate::api::rcos::projects::pitches::{submit_pitch, PitchContext, SubmitPitch};
+use crate::error::TelescopeError;
+use crate::templates::page::Page;
+use crate::templates::Template;
+use crate::web::services::auth::identity::AuthenticationCookie;
+use actix_web::http::header::LOCATION;
+use actix_web::web::{Form, ServiceConfig};
+use actix_web::{HttpRequest, HttpResponse};
+use uuid::Uuid;
+
+/// The handlebars template for the project pitch form.
+const TEMPLATE_PATH: &'static str = "projects/pitch";
+
+/// Register project pitch services.
+pub fn register(config: &mut ServiceConfig) {
+    config.service(pitch_page).service(submit);
+}
+
+/// Create the project pitch form for a user.
+async fn pitch_form(user_id: Uuid) -> Result<Template, TelescopeError> {
+    let mut form = Template::new(TEMPLATE_PATH);
+    form.fields = json!({
+        "context": PitchContext::get(user_id).await?,
+    });
+    return Ok(form);
+}
+
+/// Page to pitch a project and view the status of previous pitches.
+#[get("/projects/pitch")]
+async fn pitch_page(req: HttpRequest, auth: AuthenticationCookie) -> Result<Page, TelescopeError> {
+    let user_id: Uuid = auth.get_user_id_or_error().await?;
+    let mut form: Template = pitch_form(user_id).await?;
+
+    // Fill the form with the user's existing pitch, if they have one.
+    let existing = form["context"]["semesters"]
+        .as_array()
+        .and_then(|semesters| {
+            semesters.iter().find_map(|semester| {
+                semester["project_pitches"][0].as_object().map(|pitch| {
+                    let mut selections = json!(pitch);
+                    selections["semester"] = semester["semester_id"].clone();
+                    selections
+                })
+            })
+        });
+    if let Some(selections) = existing {
+        form["selections"] = selections;
+    }
+
+    return form.in_page(&req, "Pitch a Project").await;
+}
+
+/// Form submitted by students to pitch a project.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct PitchForm {
+    /// The semester this pitch is for.
+    semester: String,
+
+    /// The ID of the existing project being pitched. Empty for new projects.
+    #[serde(default)]
+    existing_project_id: String,
+
+    #[serde(default)]
+    proposed_title: String,
+
+    #[serde(default)]
+    proposed_description: String,
+
+    /// Comma separated list of technologies.
+    #[serde(default)]
+    proposed_stack: String,
+
+    #[serde(default)]
+    pitch_slide_url: String,
+
+    #[serde(default)]
+    proposal_url: String,
+
+    /// Checkboxes are only sent when checked.
+    is_looking_for_members: Option<bool>,
+}
+
+/// Trim a string and convert it to `None` if it is empty.
+fn non_empty(string: String) -> Option<String> {
+    let trimmed = string.trim();
+    (!trimmed.is_empty()).then(|| trimmed.to_string())
+}
+
+/// Endpoint to submit a project pitch.
+#[post("/projects/pitch")]
+async fn submit(
+    req: HttpRequest,
+    auth: AuthenticationCookie,
+    Form(form): Form<PitchForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    let user_id: Uuid = auth.get_user_id_or_error().await?;
+
+    // Create a form to send back if there are any issues.
+    let mut return_form: Template = pitch_form(user_id).await?;
+    return_form["selections"] = json!(&form);
+
+    // Find the selected semester. Only current semesters the user is enrolled in are listed.
+    let semester = return_form["context"]["semesters"]
+        .as_array()
+        .and_then(|semesters| {
+            semesters
+                .iter()
+                .find(|s| s["semester_id"] == form.semester.as_str())
+        })
+        .cloned();
+
+    match semester {
+        None => {
+            return_form["issues"]["semester"] =
+                json!("Please select a current semester you are enrolled in.");
+        }
+        // Approved pitches are final.
+        Some(semester) if semester["project_pitches"][0]["is_approved"] == true => {
+            return_form["issues"]["semester"] =
+                json!("Your pitch for this semester was already approved.");
+        }
+        _ => {}
+    }
+
+    // Check the existing project, if there is one.
+    let existing_project_id: Option<i64> = match form.existing_project_id.trim() {
+        "" => None,
+        id => {
+            let parsed: Option<i64> = id.parse::<i64>().ok().filter(|id| {
+                return_form["context"]["projects"]
+                    .as_array()
+                    .map(|projects| projects.iter().any(|p| p["project_id"] == *id))
+                    .unwrap_or(false)
+            });
+
+            return_form["selections"]["existing_project_id"] = json!(parsed);
+            if parsed.is_none() {
+                return_form["issues"]["existing_project_id"] =
+                    json!("Please select an existing project.");
+            }
+            parsed
+        }
+    };
+
+    let proposed_title: Option<String> = non_empty(form.proposed_title);
+
+    // New projects need a title.
+    if existing_project_id.is_none() && proposed_title.is_none() {
+        return_form["issues"]["proposed_title"] = json!("New projects need a title.");
+    }
+
+    // Return the form if there are any issues.
+    if !return_form["issues"].is_null() {
+        let page = return_form.in_page(&req, "Pitch a Project").await?;
+        return Err(TelescopeError::InvalidForm(page));
+    }
+
+    SubmitPitch::execute(submit_pitch::Variables {
+        semester_id: form.semester,
+        user_id,
+        existing_project_id,
+        proposed_title,
+        proposed_description: non_empty(form.proposed_description),
+        proposed_stack: non_empty(form.proposed_stack),
+        pitch_slide_url: non_empty(form.pitch_slide_url),
+        proposal_url: non_empty(form.proposal_url),
+        is_looking_for_members: form.is_looking_for_members.unwrap_or(false),
+    })
+    .await?;
+
+    // Send the user back to the pitch page, which shows the status of their pitch.
+    return Ok(HttpResponse::Found()
+        .header(LOCATION, "/projects/pitch")
+        .finish());
+}
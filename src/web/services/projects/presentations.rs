@@ -0,0 +1,89 @@
+//! Services for projects to submit their end of semester presentations.
+
+use crate::api::rcos::projects::authorization_for::AuthorizationFor;
+use crate::api::rcos::projects::presentations::{ProjectPresentations, SubmitProjectPresentation};
+use crate::error::TelescopeError;
+use crate::web::services::auth::identity::AuthenticationCookie;
+use actix_web::http::header::LOCATION;
+use actix_web::web::{Form, Path, ServiceConfig};
+use actix_web::HttpResponse;
+use url::Url;
+use uuid::Uuid;
+
+/// Register project presentation services.
+pub fn register(config: &mut ServiceConfig) {
+    config.service(submit);
+}
+
+/// Form submitted by project leads with their presentation.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct PresentationForm {
+    semester_id: String,
+    presentation_url: String,
+
+    /// Checkboxes are only submitted when checked.
+    #[serde(default)]
+    is_draft: Option<String>,
+}
+
+/// Endpoint for project leads and coordinators to submit the presentation of a project for a
+/// current semester.
+#[post("/project/{project_id}/presentation")]
+async fn submit(
+    auth: AuthenticationCookie,
+    Path(project_id): Path<i64>,
+    Form(form): Form<PresentationForm>,
+) -> Result<HttpResponse, TelescopeError> {
+    let user_id: Uuid = auth.get_user_id_or_error().await?;
+
+    // Project leads and staff submit presentations.
+    if !AuthorizationFor::get(Some(user_id))
+        .await?
+        .can_edit(project_id)
+    {
+        return Err(TelescopeError::Forbidden);
+    }
+
+    // Presentations are only submitted for current semesters the project has members in.
+    let context = ProjectPresentations::get(project_id).await?;
+    if !context
+        .semesters
+        .iter()
+        .any(|semester| semester.semester_id == form.semester_id)
+    {
+        return Err(TelescopeError::BadRequest {
+            header: "Invalid Semester".into(),
+            message: "Presentations can only be submitted for current semesters the project \
+                has members in."
+                .into(),
+            show_status_code: false,
+        });
+    }
+
+    // Only accept web links.
+    let presentation_url: String = form.presentation_url.trim().to_string();
+    let is_valid: bool = Url::parse(presentation_url.as_str())
+        .map(|url| url.scheme() == "http" || url.scheme() == "https")
+        .unwrap_or(false);
+    if !is_valid {
+        return Err(TelescopeError::BadRequest {
+            header: "Invalid Presentation Link".into(),
+            message: "The presentation link must be a web address starting with http:// or \
+                https://."
+                .into(),
+            show_status_code: false,
+        });
+    }
+
+    SubmitProjectPresentation::execute(
+        project_id,
+        form.semester_id,
+        presentation_url,
+        form.is_draft.is_some(),
+    )
+    .await?;
+
+    return Ok(HttpResponse::Found()
+        .header(LOCATION, format!("/project/{}", project_id))
+        .finish());
+}
@@ -7,6 +7,9 @@ use crate::error::TelescopeError;
 use crate::web::services::auth::identity::Identity;
 use crate::api::rcos::projects::get_by_id::{project::ProjectProject, Project};
 use crate::api::rcos::projects::authorization_for::{AuthorizationFor, UserProjectAuthorization};
+use crate::api::rcos::projects::join_requests::{JoinRequestContext, ProjectMembers};
+use crate::api::rcos::projects::presentations::ProjectPresentations;
+use crate::web::services::projects::activity::project_activity;
 
 
 const TEMPLATE_PATH: &'static str = "projects/page";
@@ -34,17 +37,69 @@ pub async fn project(
 
     let project = project.unwrap();
 
-    let can_edit = authorization.can_edit();
+    let can_edit = authorization.can_edit(project_id);
 
     if !authorization.can_view() { // currently not implemented (or needed)
         return Err(TelescopeError::NotAuthenticated)
     }
 
+    // Get the project members by semester.
+    let members = ProjectMembers::get(project_id).await?;
+
+    // Get the GitHub activity on the project's repositories for each of those semesters.
+    // If it can't be retrieved, render the page without it.
+    let activity = project_activity(&project, &members)
+        .await
+        .unwrap_or_else(|err| {
+            warn!("Could not get GitHub activity for project {}: {}", project_id, err);
+            None
+        });
+
+    // Get the current semesters, to let the viewer request to join and to let project leads
+    // handle join requests.
+    let join_context = match viewer {
+        Some(user_id) => JoinRequestContext::get(project_id, user_id).await?,
+        None => Vec::new(),
+    };
+
+    // The most recent current semester the viewer is enrolled in, if they can join this project.
+    let join_semester = JoinRequestContext::join_semester(&join_context)
+        .filter(|(_, enrollment)| enrollment.project_id != Some(project_id))
+        .map(|(semester, _)| semester);
+
+    // Check if the viewer already has a pending request to join.
+    let has_requested: bool = join_semester
+        .map(|semester| {
+            semester
+                .project_join_requests
+                .iter()
+                .any(|request| Some(request.user.id) == viewer)
+        })
+        .unwrap_or(false);
+
+    // Get the project's presentations. Drafts are only shown to project leads and staff.
+    let presentations = ProjectPresentations::get(project_id).await?;
+    let visible_presentations: Vec<_> = presentations
+        .project_presentations
+        .iter()
+        .filter(|presentation| can_edit || !presentation.is_draft)
+        .collect();
+
     let mut template = Template::new(TEMPLATE_PATH);
 
     template.fields = json!({
         "project": &project,
         "auth": authorization,
+        "can_edit": can_edit,
+        "members": members,
+        "activity": activity,
+        "join_semester": join_semester,
+        "has_requested": has_requested,
+        // Only project leads and staff see pending join requests.
+        "join_requests": can_edit.then(|| &join_context),
+        "presentations": visible_presentations,
+        // Only project leads and staff can submit presentations.
+        "presentation_semesters": can_edit.then(|| &presentations.semesters),
     });
 
     let mut tags = Tags::default();
@@ -0,0 +1,51 @@
+//! Small group page.
+//!
+//! Coordinators manage small groups from the coordinator panel.
+
+use crate::api::rcos::small_groups::SmallGroup;
+use crate::env::global_config;
+use crate::error::TelescopeError;
+use crate::templates::page::Page;
+use crate::templates::Template;
+use actix_web::web::{Path, ServiceConfig};
+use actix_web::HttpRequest;
+
+/// The handlebars template for the small group page.
+const TEMPLATE_PATH: &'static str = "small_groups/page";
+
+/// Register small group services.
+pub fn register(config: &mut ServiceConfig) {
+    config.service(small_group_page);
+}
+
+/// Page showing the projects, members, mentors, meetings, and Discord channels of a small
+/// group.
+#[get("/small_group/{small_group_id}")]
+async fn small_group_page(
+    req: HttpRequest,
+    Path(small_group_id): Path<i64>,
+) -> Result<Page, TelescopeError> {
+    let small_group =
+        SmallGroup::get(small_group_id)
+            .await?
+            .ok_or(TelescopeError::resource_not_found(
+                "Small Group Not Found",
+                "Could not find a small group for this ID.",
+            ))?;
+
+    // Count the students across every project in the small group.
+    let member_count: usize = small_group
+        .small_group_projects
+        .iter()
+        .map(|project| project.project.enrollments.len())
+        .sum();
+
+    let mut template = Template::new(TEMPLATE_PATH);
+    template.fields = json!({
+        "small_group": &small_group,
+        "member_count": member_count,
+        // Used to link to the Discord channels.
+        "discord_guild_id": global_config().discord_config.rcos_guild_id,
+    });
+    return template.in_page(&req, small_group.title.as_str()).await;
+}
@@ -1,4 +1,4 @@
-use crate::api::discord::global_discord_client;
+use crate::api::discord::{global_discord_client, on_discord_runtime};
 use crate::api::rcos::users::accounts::lookup::AccountLookup;
 use crate::api::rcos::users::{delete::DeleteUser, profile::Profile, UserAccountType};
 use crate::env::global_config;
@@ -46,8 +46,7 @@ pub async fn profile_delete(
         let rcos_guild = global_config().discord_config.rcos_guild_id();
 
         // Kick the user from the RCOS guild.
-        global_discord_client()
-            .kick_member(rcos_guild, discord_id)
+        on_discord_runtime(global_discord_client().kick_member(rcos_guild, discord_id))
             .await
             .map_err(TelescopeError::serenity_error)?;
     }
@@ -1,6 +1,8 @@
 //! Page and service to let users into RCOS Discord and give them the verified role.
 
-use crate::api::discord::{global_discord_client, rcos_discord_verified_role_id};
+use crate::api::discord::{
+    global_discord_client, on_discord_runtime, rcos_discord_verified_role_id,
+};
 use crate::api::rcos::users::discord_whois::DiscordWhoIs;
 use crate::error::TelescopeError;
 
@@ -9,8 +11,8 @@ use crate::web::services::auth::identity::AuthenticationCookie;
 use actix_web::HttpResponse;
 use reqwest::header::LOCATION;
 use serenity::builder::EditMember;
+use serenity::json::hashmap_to_json_map;
 use serenity::model::prelude::RoleId;
-use serenity::utils;
 
 /// Let users into the RCOS discord.
 #[get("/join_discord")]
@@ -95,11 +97,10 @@ pub async fn handle(auth: AuthenticationCookie) -> Result<HttpResponse, Telescop
         .ok_or(TelescopeError::ise("Could not get Verified role ID."))?;
 
     // Add user to Discord with verified role and nickname.
-    
+
     discord
         .add_to_rcos_guild(Some(nickname.clone()), vec![verified_role])
         .await?;
-    
 
     // If user was already in the discord, they may not have the verified role, and the
     // previous call will do nothing. Make an additional call here to add the verified role
@@ -110,21 +111,32 @@ pub async fn handle(auth: AuthenticationCookie) -> Result<HttpResponse, Telescop
     let rcos_discord_guild = global_config().discord_config.rcos_guild_id();
 
     // Make the call to add the verified role
-    global_discord_client()
-        .add_member_role(rcos_discord_guild, discord_user_id, verified_role.0)
-        .await
-        .map_err(TelescopeError::serenity_error)?;
-
+    on_discord_runtime(global_discord_client().add_member_role(
+        rcos_discord_guild,
+        discord_user_id,
+        verified_role.0,
+        None,
+    ))
+    .await
+    .map_err(TelescopeError::serenity_error)?;
 
     // If user was already in the discord, they might not have a correctly formatted nickname
     // Therefore we need to add it manually
     let mut builder = EditMember::default();
     builder.nickname(nickname);
-    let map = utils::hashmap_to_json_map(builder.0);
-    global_discord_client()
-        .edit_member(global_config().discord_config.rcos_guild_id(), discord_user_id, &map)
-        .await
-        .map_err(TelescopeError::serenity_error)?;
+    let map = hashmap_to_json_map(builder.0);
+    on_discord_runtime(async move {
+        global_discord_client()
+            .edit_member(
+                global_config().discord_config.rcos_guild_id(),
+                discord_user_id,
+                &map,
+                None,
+            )
+            .await
+    })
+    .await
+    .map_err(TelescopeError::serenity_error)?;
 
     // On success, redirect user back to their profile.
     Ok(HttpResponse::Found()
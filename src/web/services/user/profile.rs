@@ -1,6 +1,7 @@
 //! Profile services.
 
-use crate::api::discord::{self, global_discord_client};
+use crate::api::discord::{self, global_discord_client, on_discord_runtime};
+use crate::api::rcos::users::attendance_summary::AttendanceSummary;
 use crate::api::rcos::users::edit_profile::{EditProfileContext, SaveProfileEdits};
 use crate::api::rcos::users::profile::{
     profile::{ProfileTarget, ResponseData},
@@ -16,6 +17,7 @@ use crate::web::services::auth::identity::{AuthenticationCookie, Identity};
 use actix_web::web::{Form, Path, ServiceConfig};
 use actix_web::{http::header::LOCATION, HttpRequest, HttpResponse};
 use chrono::{Datelike, Local};
+use chrono_tz::{Tz, TZ_VARIANTS};
 use serenity::model::guild::Member;
 use serenity::model::user::User;
 use std::collections::HashMap;
@@ -60,6 +62,20 @@ async fn profile(
     let mut template: Template = Template::new(TEMPLATE_NAME);
     template["data"] = json!(&response);
 
+    // Users can see their own attendance summary. Current coordinators and admins can see
+    // anyone's.
+    let can_view_attendance: bool = response
+        .viewer
+        .first()
+        .map(|viewer| {
+            viewer.id == id || viewer.role.is_admin() || !viewer.is_current_coordinator.is_empty()
+        })
+        .unwrap_or(false);
+
+    if can_view_attendance {
+        template["data"]["attendance"] = json!(AttendanceSummary::get(id).await?);
+    }
+
     // Get the target user's info.
     let target_user: &ProfileTarget = response.target.as_ref().unwrap();
     // And use it to make the page title
@@ -75,7 +91,7 @@ async fn profile(
     if let Some(target_discord_id) = target_discord_id.and_then(|s| s.parse::<u64>().ok()) {
         // Get target user info.
         let target_user: Result<User, serenity::Error> =
-            global_discord_client().get_user(target_discord_id).await;
+            on_discord_runtime(global_discord_client().get_user(target_discord_id)).await;
 
         // Check to make sure target user info was available.
         match target_user {
@@ -109,10 +125,10 @@ async fn profile(
         let rcos_discord: u64 = global_config().discord_config.rcos_guild_id();
 
         // Target user as member of RCOS discord.
-        let membership: Option<Member> = global_discord_client()
-            .get_member(rcos_discord, target_discord_id)
-            .await
-            .ok();
+        let membership: Option<Member> =
+            on_discord_runtime(global_discord_client().get_member(rcos_discord, target_discord_id))
+                .await
+                .ok();
 
         // Get "Verified" role ID if available.
         let verified_role_id = discord::rcos_discord_verified_role_id()
@@ -189,7 +205,8 @@ fn make_settings_form() -> Template {
 
     // The max entry year should always be the current year.
     form.fields = json!({
-        "max_entry_year": Local::today().year()
+        "max_entry_year": Local::today().year(),
+        "timezones": TZ_VARIANTS.iter().map(|tz| tz.name()).collect::<Vec<_>>(),
     });
 
     return form;
@@ -261,6 +278,9 @@ struct ProfileEdits {
     /// Entry year for RPI students.
     #[serde(default)]
     cohort: String,
+
+    /// Name of the timezone to show times in, from the IANA timezone database.
+    timezone: String,
 }
 
 /// Submission endpoint for the user settings form.
@@ -273,6 +293,7 @@ async fn save_changes(
         last_name,
         role,
         cohort,
+        timezone,
     }): Form<ProfileEdits>,
 ) -> Result<HttpResponse, TelescopeError> {
     // Get authenticated user ID. This API call gets duplicated in the context creation unfortunately.
@@ -317,6 +338,12 @@ async fn save_changes(
     form["context"]["last_name"] = json!(&last_name);
     form["context"]["cohort"] = json!(&cohort);
     form["context"]["role"] = json!(role);
+    form["context"]["timezone"] = json!(&timezone);
+
+    // Check that the timezone exists.
+    if timezone.parse::<Tz>().is_err() {
+        form["issues"]["timezone"] = json!("Please select a timezone from the list.");
+    }
 
     // Error if first or last name is empty.
     if first_name.trim().is_empty() {
@@ -333,7 +360,7 @@ async fn save_changes(
     }
 
     // Execute GraphQL mutation to save changes.
-    let user_id = SaveProfileEdits::execute(user_id, first_name, last_name, cohort, role)
+    let user_id = SaveProfileEdits::execute(user_id, first_name, last_name, cohort, role, timezone)
         .await?
         .ok_or(TelescopeError::ise(
             "Could not save changes -- user not found.",